@@ -1,11 +1,9 @@
-use core::fmt::Write;
 use std::path::Path;
 use std::{env, fs};
 
-use cargo_metadata::MetadataCommand;
-use proc_macro2::{Spacing, TokenStream, TokenTree};
+use cargo_metadata::{Metadata, MetadataCommand};
 use quote::{format_ident, quote};
-use syn::parse_file;
+use syn::{ItemEnum, parse_file};
 
 fn main() {
     let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
@@ -58,22 +56,37 @@ fn main() {
         })
         .expect("Failed to find BitName enum");
 
-    // Generate the match arms
+    check_bits(&metadata, bit_name_enum);
+
+    // Generate the match arms, bits whose feature is disabled are not linked in
     let match_arms = bit_name_enum.variants.iter().map(|variant| {
         let variant_ident = &variant.ident;
-        let module_name = format_ident!(
-            "{}",
-            heck::AsSnakeCase(variant_ident.to_string()).to_string()
-        );
-        quote! {
-            BitName::#variant_ident => #module_name::run(),
+        let module_name = heck::AsSnakeCase(variant_ident.to_string()).to_string();
+        let feature = format!("CARGO_FEATURE_{}", module_name.to_uppercase());
+
+        if env::var_os(feature).is_some() {
+            let module_ident = format_ident!("{}", module_name);
+            quote! {
+                BitName::#variant_ident => {
+                    #module_ident::run();
+                    Ok(())
+                }
+            }
+        } else {
+            let error = format!(
+                "{variant_ident} is not included in this build, enable the `{module_name}` feature of bevy_bits_wasm"
+            );
+            quote! {
+                BitName::#variant_ident => Err(#error),
+            }
         }
     });
 
     // Generate the full implementation
     let generated_code = quote! {
         impl BitRunner {
-            fn run(&self) {
+            #[allow(clippy::unnecessary_wraps, reason = "every bit can be enabled")]
+            fn run(&self) -> Result<(), &'static str> {
                 match self.bit_name {
                     #(#match_arms)*
                 }
@@ -81,7 +94,8 @@ fn main() {
         }
     };
 
-    let output = token_stream_to_string(&generated_code);
+    let generated_file = syn::parse2(generated_code).expect("Failed to parse generated code");
+    let output = prettyplease::unparse(&generated_file);
 
     let out_dir = env::var_os("OUT_DIR").expect("There should be an OUT_DIR");
 
@@ -90,61 +104,76 @@ fn main() {
 
     fs::write(&dest_path, output).expect("Failed to write generated code");
 
-    // Format the output
-    std::process::Command::new("rustfmt")
-        .arg(&dest_path)
-        .output()
-        .expect("failed to execute rustfmt");
-
+    for package in metadata.workspace_packages() {
+        if package.metadata.get("bit").is_some() {
+            println!("cargo:rerun-if-changed={}", package.manifest_path);
+        }
+    }
+    println!("cargo:rerun-if-changed=Cargo.toml");
     println!("cargo:rerun-if-changed=../../Cargo.lock");
 }
 
-fn token_stream_to_string(ts: &TokenStream) -> String {
-    let mut result = String::new();
-    let mut iter = ts.clone().into_iter().peekable();
-
-    while let Some(token_tree) = iter.next() {
-        match token_tree {
-            TokenTree::Group(group) => {
-                let delimiter = match group.delimiter() {
-                    proc_macro2::Delimiter::Parenthesis => ("(", ")"),
-                    proc_macro2::Delimiter::Brace => ("{", "}"),
-                    proc_macro2::Delimiter::Bracket => ("[", "]"),
-                    proc_macro2::Delimiter::None => ("", ""),
-                };
-                write!(
-                    &mut result,
-                    "{}{}{}",
-                    delimiter.0,
-                    token_stream_to_string(&group.stream()),
-                    delimiter.1
-                )
-                .expect("Failed to write to string");
-            }
-            TokenTree::Ident(ident) => {
-                result.push_str(&ident.to_string());
-                if let Some(TokenTree::Punct(punct)) = iter.peek() {
-                    if punct.as_char() == ':' || punct.as_char() == '.' {
-                        // Don't add space before ':' or '.'
-                    } else {
-                        result.push(' ');
-                    }
-                } else {
-                    result.push(' ');
-                }
-            }
-            TokenTree::Punct(punct) => {
-                result.push(punct.as_char());
-                if punct.spacing() == Spacing::Alone {
-                    result.push(' ');
-                }
-            }
-            TokenTree::Literal(lit) => {
-                result.push_str(&lit.to_string());
-                result.push(' ');
-            }
+/// Cross-checks `BitName` against the workspace bits, which are the members with a
+/// `[package.metadata.bit]` table
+///
+/// Every variant needs a crate named after it, depended on and behind a feature of the
+/// same name, otherwise the generated code fails to compile with an unhelpful error.
+/// Bits missing from `BitName` still build, but can never be launched by the platform.
+fn check_bits(metadata: &Metadata, bit_name_enum: &ItemEnum) {
+    let this_package = metadata
+        .workspace_packages()
+        .into_iter()
+        .find(|p| p.name == "bevy_bits_wasm")
+        .expect("Failed to find bevy_bits_wasm package");
+    let bits: Vec<_> = metadata
+        .workspace_packages()
+        .into_iter()
+        .filter(|package| package.metadata.get("bit").is_some())
+        .map(|package| package.name.replace('-', "_"))
+        .collect();
+
+    let variants: Vec<_> = bit_name_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let module_name = heck::AsSnakeCase(variant.ident.to_string()).to_string();
+            (variant.ident.to_string(), module_name)
+        })
+        .collect();
+
+    let mut problems = Vec::new();
+    for (variant, module_name) in &variants {
+        if !bits.contains(module_name) {
+            problems.push(format!(
+                "BitName::{variant} has no `{module_name}` crate with [package.metadata.bit] in the workspace"
+            ));
+        }
+        if !this_package
+            .dependencies
+            .iter()
+            .any(|dependency| dependency.name.replace('-', "_") == *module_name)
+        {
+            problems.push(format!(
+                "BitName::{variant} needs `{module_name}` as an optional dependency of bevy_bits_wasm"
+            ));
+        }
+        if !this_package.features.contains_key(module_name) {
+            problems.push(format!(
+                "BitName::{variant} needs a `{module_name} = [\"dep:{module_name}\"]` feature in bevy_bits_wasm"
+            ));
+        }
+    }
+    assert!(
+        problems.is_empty(),
+        "BitName and the workspace bits do not match:\n  - {}",
+        problems.join("\n  - ")
+    );
+
+    for bit in &bits {
+        if !variants.iter().any(|(_, module_name)| module_name == bit) {
+            println!(
+                "cargo:warning={bit} is not registered in ribbit_bits::BitName, the platform cannot launch it"
+            );
         }
     }
-
-    result.trim().to_string()
 }
@@ -43,6 +43,5 @@ pub fn main_wasm() -> Result<(), JsValue> {
         return Err(JsValue::from_str(&format!("Invalid BitName: {bit_name}")));
     };
     console::log_1(&format!("Starting {}", bit_runner.bit_name).into());
-    bit_runner.run();
-    Ok(())
+    bit_runner.run().map_err(JsValue::from_str)
 }
@@ -0,0 +1,169 @@
+"""Packs the Twemoji PNGs into EmojiAtlas.png and writes EmojiAtlas.manifest.ron
+
+Usage: python generate.py [--manifest-only] <twemoji>/assets/72x72 <emoji-test.txt>
+
+Cells are filled row by row in codepoint order, each emoji being scaled to 64x64.
+Packing needs ImageMagick, then run convert.bat to compress the atlas to EmojiAtlas.ktx2.
+With --manifest-only the atlas is left as is, and only the manifest is written.
+
+Shortcodes are the CLDR names of emoji-test.txt, from https://unicode.org/Public/emoji/,
+e.g. "grinning_face", "woman_technologist" or "flag_france". Flags also get their region
+code, e.g. "flag_fr", and a few emojis the short names chat apps use, e.g. "apple".
+"""
+
+import re
+import subprocess
+import sys
+import tempfile
+import unicodedata
+from pathlib import Path
+
+CELL_SIZE = 64
+GRID = 64
+
+VARIATION_SELECTOR = 0xFE0F
+ZERO_WIDTH_JOINER = 0x200D
+REGIONAL_INDICATOR_A = 0x1F1E6
+
+# Extra shortcodes, keyed by the CLDR shortcode
+ALIASES = {
+    "red_apple": ["apple"],
+    "red_heart": ["heart"],
+    "thumbs_up": ["thumbsup", "+1"],
+    "thumbs_down": ["thumbsdown", "-1"],
+    "face_with_tears_of_joy": ["joy"],
+    "smiling_face_with_heart_eyes": ["heart_eyes"],
+    "pile_of_poo": ["poop"],
+    "party_popper": ["tada"],
+    "dog_face": ["dog"],
+    "cat_face": ["cat"],
+}
+
+SYMBOLS = {"#": "hash", "*": "asterisk"}
+
+
+def codepoints(path):
+    return [int(part, 16) for part in path.stem.split("-")]
+
+
+def file_key(points):
+    """Codepoints of the Twemoji file of an emoji, which drops the variation selectors
+    of emojis that aren't joined with a zero width joiner"""
+    if ZERO_WIDTH_JOINER in points:
+        return tuple(points)
+    return tuple(point for point in points if point != VARIATION_SELECTOR)
+
+
+def shortcode(name):
+    name = " ".join(SYMBOLS.get(word, word) for word in name.split(" "))
+    # Drops accents, e.g. "piñata" becomes "pinata"
+    name = unicodedata.normalize("NFKD", name)
+    name = "".join(c for c in name if not unicodedata.combining(c))
+    return re.sub(r"[^a-z0-9]+", "_", name.lower()).strip("_")
+
+
+def read_emoji_test(path):
+    """Maps the Twemoji file codepoints to the fully-qualified sequence and its name"""
+    emojis = {}
+    for line in Path(path).read_text(encoding="utf-8").splitlines():
+        match = re.match(r"([0-9A-F ]+?)\s*; (fully-qualified|component)\s*# \S+ E[\d.]+ (.+)", line)
+        if match:
+            points = [int(part, 16) for part in match.group(1).split()]
+            emojis[file_key(points)] = (points, match.group(3))
+    return emojis
+
+
+def shortcodes(points, name):
+    if name is None:
+        name = unicodedata.name(chr(points[0]), "") if len(points) == 1 else ""
+    if not name:
+        return []
+
+    names = [shortcode(name)]
+    names += ALIASES.get(names[0], [])
+    if len(points) == 2 and all(
+        REGIONAL_INDICATOR_A <= point < REGIONAL_INDICATOR_A + 26 for point in points
+    ):
+        names.append("flag_" + "".join(chr(ord("a") + point - REGIONAL_INDICATOR_A) for point in points))
+    return names
+
+
+def write_manifest(files, emojis, path):
+    taken = set()
+    lines = [
+        "// Maps atlas cells to their emoji, regenerate it whenever EmojiAtlas.ktx2 changes.",
+        "// Each entry is (index: <atlas cell>, sequence: \"<emoji codepoints>\", shortcodes: [\"name\", ...]).",
+        "// Generated by generate.py, do not edit by hand.",
+        "(",
+        "    emojis: [",
+    ]
+    for index, file in enumerate(files):
+        points, name = emojis.get(file_key(codepoints(file)), (codepoints(file), None))
+        sequence = "".join(chr(point) for point in points)
+        # The first emoji keeps a shortcode, e.g. before its skin tone variants
+        names = [name for name in shortcodes(points, name) if name not in taken]
+        taken.update(names)
+        quoted = ", ".join(f'"{name}"' for name in names)
+        lines.append(f'        (index: {index}, sequence: "{sequence}", shortcodes: [{quoted}]),')
+    lines += ["    ],", ")", ""]
+    path.write_text("\n".join(lines), encoding="utf-8")
+
+
+def pack_atlas(files, path):
+    # Listed in a file, the command line being too short for thousands of paths
+    with tempfile.NamedTemporaryFile("w", suffix=".txt", delete=False) as listing:
+        listing.write("\n".join(str(file) for file in files))
+    subprocess.run(
+        [
+            "magick",
+            "montage",
+            "@" + listing.name,
+            "-background",
+            "none",
+            "-resize",
+            f"{CELL_SIZE}x{CELL_SIZE}",
+            "-tile",
+            f"{GRID}x",
+            "-geometry",
+            "+0+0",
+            path,
+        ],
+        check=True,
+    )
+    Path(listing.name).unlink()
+    # Montage only makes as many rows as needed, the atlas has the full grid
+    subprocess.run(
+        [
+            "magick",
+            path,
+            "-background",
+            "none",
+            "-gravity",
+            "northwest",
+            "-extent",
+            f"{CELL_SIZE * GRID}x{CELL_SIZE * GRID}",
+            path,
+        ],
+        check=True,
+    )
+
+
+def main():
+    args = sys.argv[1:]
+    manifest_only = "--manifest-only" in args
+    args = [arg for arg in args if arg != "--manifest-only"]
+    if len(args) != 2:
+        sys.exit(__doc__)
+
+    files = sorted(Path(args[0]).glob("*.png"), key=codepoints)
+    if len(files) > GRID * GRID:
+        sys.exit(f"{len(files)} emojis do not fit in the {GRID}x{GRID} atlas")
+
+    here = Path(__file__).parent
+    write_manifest(files, read_emoji_test(args[1]), here / "EmojiAtlas.manifest.ron")
+    if not manifest_only:
+        pack_atlas(files, here / "EmojiAtlas.png")
+
+
+if __name__ == "__main__":
+    main()
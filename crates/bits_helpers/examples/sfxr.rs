@@ -0,0 +1,59 @@
+//! Renders an sfxr preset to a WAV file
+//!
+//! ```sh
+//! cargo run -p bits_helpers --example sfxr -- coin 42 coin.wav
+//! cargo run -p bits_helpers --example sfxr -- explosion 7 boom.wav --mutate 3
+//! ```
+
+use std::env;
+use std::process::ExitCode;
+
+use bits_helpers::sfxr::{SAMPLE_RATE, SfxrPreset, encode_wav};
+
+const USAGE: &str =
+    "usage: sfxr <coin|hit|jump|blip|powerup|explosion> <seed> <output.wav> [--mutate <seed>]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (Some(preset), Some(seed), Some(output)) = (args.first(), args.get(1), args.get(2)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let Ok(preset) = preset.parse::<SfxrPreset>() else {
+        eprintln!("unknown preset {preset}\n{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    let Ok(seed) = seed.parse() else {
+        eprintln!("seed must be a number\n{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let mut params = preset.generate(seed);
+    match (args.get(3).map(String::as_str), args.get(4)) {
+        (None, _) => {}
+        (Some("--mutate"), Some(mutation)) => {
+            let Ok(mutation) = mutation.parse() else {
+                eprintln!("mutation seed must be a number\n{USAGE}");
+                return ExitCode::FAILURE;
+            };
+            params = params.mutate(mutation);
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let samples = params.synthesize();
+    if let Err(error) = std::fs::write(output, encode_wav(&samples, SAMPLE_RATE)) {
+        eprintln!("cannot write {output}: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    println!(
+        "{preset:?} #{seed}: {:.2}s written to {output}",
+        samples.len() as f32 / SAMPLE_RATE as f32
+    );
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,411 @@
+use core::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_kira_audio::prelude::{
+    AudioApp, AudioChannel, AudioControl, AudioInstance, AudioPlugin, AudioSource, AudioTween,
+    Frame, PlaybackState, StaticSoundData, StaticSoundSettings, Volume,
+};
+
+use crate::launch_options::launch_flag;
+use crate::sfxr::{SAMPLE_RATE, SfxrParams};
+
+/// Music volume while a ducking sound plays, relative to its normal volume
+const DUCKED_MUSIC_VOLUME: f32 = 0.3;
+const MUSIC_FADE: Duration = Duration::from_millis(200);
+
+/// Plays a bit's sound effects and background music
+///
+/// Bits map [`SoundEvent`]s to sounds in a [`SoundBank`], then send [`PlaySound`]
+/// events from their systems:
+///
+/// ```ignore
+/// app.add_plugins(BitAudioPlugin::new(
+///     SoundBank::default()
+///         .with(SoundEvent::Tap, SoundDef::new("audio/tap.ogg").with_pitch_variation(0.1))
+///         .with(SoundEvent::Success, SoundDef::synth(SfxrPreset::Coin.generate(1)).ducking_music())
+///         .with_music("audio/theme.ogg"),
+/// ));
+///
+/// fn on_tap(mut sounds: EventWriter<PlaySound>) {
+///     sounds.send(PlaySound(SoundEvent::Tap));
+/// }
+/// ```
+///
+/// Audio starts muted when the bit is launched with the `muted` option.
+pub struct BitAudioPlugin {
+    pub bank: SoundBank,
+}
+
+impl BitAudioPlugin {
+    pub const fn new(bank: SoundBank) -> Self {
+        Self { bank }
+    }
+}
+
+impl Plugin for BitAudioPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<AudioPlugin>() {
+            app.add_plugins(AudioPlugin);
+        }
+
+        if !app.world().contains_resource::<SoundSettings>() {
+            app.insert_resource(SoundSettings {
+                muted: launch_flag("muted"),
+                ..default()
+            });
+        }
+
+        app.add_audio_channel::<SfxChannel>()
+            .add_audio_channel::<MusicChannel>()
+            .add_event::<PlaySound>()
+            .add_event::<MusicEvent>()
+            .insert_resource(self.bank.clone())
+            .init_resource::<SoundPlayer>()
+            .add_systems(Startup, load_sound_bank)
+            .add_systems(Update, (play_sounds, update_music).chain());
+    }
+}
+
+/// Audio channel of sound effects
+#[derive(Resource)]
+pub struct SfxChannel;
+
+/// Audio channel of background music
+#[derive(Resource)]
+pub struct MusicChannel;
+
+/// Something that happened in a bit and deserves a sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEvent {
+    Tap,
+    Success,
+    Fail,
+    Combo,
+    Countdown,
+    /// Any other sound specific to a bit
+    Named(&'static str),
+}
+
+/// Where the samples of a sound come from
+#[derive(Debug, Clone)]
+pub enum SoundSource {
+    Asset(String),
+    /// Rendered by the [`sfxr`](crate::sfxr) synthesizer at startup
+    Synth(SfxrParams),
+}
+
+/// How a sound event is played
+#[derive(Debug, Clone)]
+pub struct SoundDef {
+    pub source: SoundSource,
+    pub volume: f32,
+    /// Playback rate is randomized up to this much around 1, e.g. `0.1` for ±10%
+    pub pitch_variation: f32,
+    /// Minimum time between two plays, plays in between are dropped
+    pub cooldown: Duration,
+    /// Maximum number of instances playing at once, further plays are dropped
+    pub max_voices: usize,
+    /// Lowers the music while the sound plays
+    pub ducks_music: bool,
+}
+
+impl SoundDef {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self::from_source(SoundSource::Asset(path.into()))
+    }
+
+    /// A sound synthesized from sfxr parameters, for bits without audio assets
+    pub const fn synth(params: SfxrParams) -> Self {
+        Self::from_source(SoundSource::Synth(params))
+    }
+
+    const fn from_source(source: SoundSource) -> Self {
+        Self {
+            source,
+            volume: 1.0,
+            pitch_variation: 0.0,
+            cooldown: Duration::ZERO,
+            max_voices: 4,
+            ducks_music: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_pitch_variation(mut self, pitch_variation: f32) -> Self {
+        self.pitch_variation = pitch_variation;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_voices(mut self, max_voices: usize) -> Self {
+        self.max_voices = max_voices;
+        self
+    }
+
+    #[must_use]
+    pub const fn ducking_music(mut self) -> Self {
+        self.ducks_music = true;
+        self
+    }
+}
+
+/// Sounds of a bit, and the music it starts with
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SoundBank {
+    sounds: HashMap<SoundEvent, SoundDef>,
+    music: Option<String>,
+}
+
+impl SoundBank {
+    #[must_use]
+    pub fn with(mut self, event: SoundEvent, sound: SoundDef) -> Self {
+        self.sounds.insert(event, sound);
+        self
+    }
+
+    /// Loops `path` as background music from startup
+    #[must_use]
+    pub fn with_music(mut self, path: impl Into<String>) -> Self {
+        self.music = Some(path.into());
+        self
+    }
+}
+
+/// Plays the sound mapped to an event, if any
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PlaySound(pub SoundEvent);
+
+#[derive(Event, Debug, Clone)]
+pub enum MusicEvent {
+    /// Loops a track, replacing the current one
+    Play(String),
+    Stop,
+}
+
+/// Volumes applied on top of each sound's own volume
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SoundSettings {
+    pub muted: bool,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        Self {
+            muted: false,
+            sfx_volume: 1.0,
+            music_volume: 0.6,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct SoundPlayer {
+    sounds: HashMap<SoundEvent, (SoundDef, Handle<AudioSource>)>,
+    last_played: HashMap<SoundEvent, Duration>,
+    voices: HashMap<SoundEvent, Vec<Handle<AudioInstance>>>,
+    /// Playing sounds that duck the music
+    ducking: Vec<Handle<AudioInstance>>,
+    music: Option<Handle<AudioInstance>>,
+    /// Volume the music instance was last set to
+    music_volume: Option<f32>,
+}
+
+impl SoundPlayer {
+    /// Loops a track from its start, fading out the previous one
+    fn start_music(
+        &mut self,
+        source: Handle<AudioSource>,
+        channel: &AudioChannel<MusicChannel>,
+        volume: f32,
+    ) {
+        channel.stop().fade_out(AudioTween::linear(MUSIC_FADE));
+        self.music = Some(
+            channel
+                .play(source)
+                .looped()
+                .with_volume(f64::from(volume))
+                .linear_fade_in(MUSIC_FADE)
+                .handle(),
+        );
+        self.music_volume = Some(volume);
+    }
+}
+
+fn load_sound_bank(
+    bank: Res<SoundBank>,
+    mut player: ResMut<SoundPlayer>,
+    settings: Res<SoundSettings>,
+    asset_server: Res<AssetServer>,
+    mut sources: ResMut<Assets<AudioSource>>,
+    music_channel: Res<AudioChannel<MusicChannel>>,
+) {
+    player.sounds = bank
+        .sounds
+        .iter()
+        .map(|(&event, sound)| {
+            (
+                event,
+                (
+                    sound.clone(),
+                    match &sound.source {
+                        SoundSource::Asset(path) => asset_server.load(path.clone()),
+                        SoundSource::Synth(params) => sources.add(synthesized_source(params)),
+                    },
+                ),
+            )
+        })
+        .collect();
+
+    if let Some(path) = &bank.music {
+        player.start_music(
+            asset_server.load(path.clone()),
+            &music_channel,
+            music_volume(&settings, false),
+        );
+    }
+}
+
+/// Renders sfxr parameters into a source playable on any audio channel
+pub fn synthesized_source(params: &SfxrParams) -> AudioSource {
+    let frames: Vec<Frame> = params
+        .synthesize()
+        .into_iter()
+        .map(Frame::from_mono)
+        .collect();
+
+    AudioSource {
+        sound: StaticSoundData {
+            sample_rate: SAMPLE_RATE,
+            frames: frames.into(),
+            settings: StaticSoundSettings::default(),
+            slice: None,
+        },
+    }
+}
+
+fn play_sounds(
+    mut events: EventReader<PlaySound>,
+    mut player: ResMut<SoundPlayer>,
+    settings: Res<SoundSettings>,
+    channel: Res<AudioChannel<SfxChannel>>,
+    time: Res<Time<Real>>,
+) {
+    if settings.is_changed() && settings.muted {
+        channel.stop();
+    }
+
+    let now = time.elapsed();
+    let player = &mut *player;
+    for &PlaySound(event) in events.read() {
+        if settings.muted {
+            continue;
+        }
+        let Some((sound, source)) = player.sounds.get(&event) else {
+            warn!("No sound mapped to {event:?}");
+            continue;
+        };
+
+        if player
+            .last_played
+            .get(&event)
+            .is_some_and(|&last| now < last + sound.cooldown)
+        {
+            continue;
+        }
+
+        let voices = player.voices.entry(event).or_default();
+        voices.retain(|voice| channel.state(voice) != PlaybackState::Stopped);
+        if voices.len() >= sound.max_voices {
+            continue;
+        }
+
+        let rate = fastrand::f64()
+            .mul_add(2.0, -1.0)
+            .mul_add(f64::from(sound.pitch_variation), 1.0);
+        let voice = channel
+            .play(source.clone())
+            .with_volume(f64::from(sound.volume * settings.sfx_volume))
+            .with_playback_rate(rate)
+            .handle();
+
+        if sound.ducks_music {
+            player.ducking.push(voice.clone());
+        }
+        voices.push(voice);
+        player.last_played.insert(event, now);
+    }
+}
+
+fn update_music(
+    mut events: EventReader<MusicEvent>,
+    mut player: ResMut<SoundPlayer>,
+    settings: Res<SoundSettings>,
+    asset_server: Res<AssetServer>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+    music_channel: Res<AudioChannel<MusicChannel>>,
+    mut instances: ResMut<Assets<AudioInstance>>,
+) {
+    let player = &mut *player;
+    player
+        .ducking
+        .retain(|voice| sfx_channel.state(voice) != PlaybackState::Stopped);
+    let volume = music_volume(&settings, !player.ducking.is_empty());
+
+    for event in events.read() {
+        match event {
+            MusicEvent::Play(path) => {
+                player.start_music(asset_server.load(path.clone()), &music_channel, volume);
+            }
+            MusicEvent::Stop => {
+                music_channel
+                    .stop()
+                    .fade_out(AudioTween::linear(MUSIC_FADE));
+                player.music = None;
+            }
+        }
+    }
+
+    // The instance only exists once the channel started playing it
+    let Some(instance) = player
+        .music
+        .as_ref()
+        .and_then(|music| instances.get_mut(music))
+    else {
+        return;
+    };
+    if player
+        .music_volume
+        .is_none_or(|applied| (applied - volume).abs() > f32::EPSILON)
+    {
+        instance.set_volume(
+            Volume::Amplitude(f64::from(volume)),
+            AudioTween::linear(MUSIC_FADE),
+        );
+        player.music_volume = Some(volume);
+    }
+}
+
+fn music_volume(settings: &SoundSettings, is_ducked: bool) -> f32 {
+    if settings.muted {
+        0.0
+    } else if is_ducked {
+        settings.music_volume * DUCKED_MUSIC_VOLUME
+    } else {
+        settings.music_volume
+    }
+}
@@ -0,0 +1,228 @@
+#[cfg(target_arch = "wasm32")]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::window::{WindowFocused, WindowOccluded};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::FONT;
+use crate::i18n::Strings;
+
+const OVERLAY_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+const OVERLAY_FONT_SIZE: f32 = 40.0;
+
+/// Set when the page was hidden, until the bit pauses
+#[cfg(target_arch = "wasm32")]
+static PAGE_HIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// Pauses the bit through [`Time<Virtual>`] when the player leaves it, so its timers
+/// don't run out in the background
+///
+/// The bit pauses when the tab is hidden on wasm, and when the window loses focus or is
+/// occluded on native. A "Tap to resume" overlay covers it until the player taps, clicks
+/// or presses a key, and the bit never sees that input. Time paused by other means, e.g.
+/// the simulation's `F5`, is left alone.
+pub struct AutoPausePlugin;
+
+impl Plugin for AutoPausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AutoPause>()
+            .add_systems(
+                PreUpdate,
+                (player_left.pipe(pause_when_left), resume_on_input)
+                    .chain()
+                    .after(InputSystem)
+                    .before(UiSystem::Focus),
+            )
+            .add_systems(Update, update_overlay.run_if(resource_changed::<AutoPause>));
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(Startup, listen_visibility_change);
+    }
+}
+
+/// Whether the bit is paused because the player left it
+#[derive(Resource, Default)]
+pub struct AutoPause {
+    paused: bool,
+}
+
+impl AutoPause {
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+#[derive(Component)]
+struct AutoPauseOverlay;
+
+#[cfg(target_arch = "wasm32")]
+fn listen_visibility_change() {
+    let window = web_sys::window().expect("no global `window` exists");
+    let document = window.document().expect("no document exists");
+    let closure = Closure::wrap(Box::new(move || {
+        let hidden = web_sys::window()
+            .and_then(|window| window.document())
+            .is_some_and(|document| document.hidden());
+        if hidden {
+            PAGE_HIDDEN.store(true, Ordering::Relaxed);
+        }
+    }) as Box<dyn FnMut()>);
+
+    document
+        .add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref())
+        .expect("failed to add visibilitychange event listener");
+
+    closure.forget(); // Leaks memory, but ensures the closure lives for the lifetime of the program
+}
+
+#[cfg(target_arch = "wasm32")]
+fn player_left() -> bool {
+    PAGE_HIDDEN.swap(false, Ordering::Relaxed)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn player_left(
+    mut focused: EventReader<WindowFocused>,
+    mut occluded: EventReader<WindowOccluded>,
+) -> bool {
+    // Only the latest state matters, every event being read so none is left for later
+    let unfocused = focused.read().last().is_some_and(|event| !event.focused);
+    let occluded = occluded.read().last().is_some_and(|event| event.occluded);
+    unfocused || occluded
+}
+
+fn pause_when_left(
+    In(left): In<bool>,
+    mut time: ResMut<Time<Virtual>>,
+    mut auto_pause: ResMut<AutoPause>,
+) {
+    if left && !time.is_paused() {
+        time.pause();
+        auto_pause.paused = true;
+    }
+}
+
+fn resume_on_input(
+    mut time: ResMut<Time<Virtual>>,
+    mut auto_pause: ResMut<AutoPause>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut touches: ResMut<Touches>,
+) {
+    if !auto_pause.paused {
+        return;
+    }
+
+    if mouse.get_just_pressed().next().is_some()
+        || keys.get_just_pressed().next().is_some()
+        || touches.any_just_pressed()
+    {
+        time.unpause();
+        auto_pause.paused = false;
+    }
+
+    // The bit doesn't react to the input made while paused, including the one resuming
+    mouse.reset_all();
+    keys.reset_all();
+    touches.reset_all();
+}
+
+fn update_overlay(
+    mut commands: Commands,
+    auto_pause: Res<AutoPause>,
+    asset_server: Res<AssetServer>,
+    strings: Strings,
+    overlays: Query<Entity, With<AutoPauseOverlay>>,
+) {
+    if !auto_pause.paused {
+        for overlay in &overlays {
+            commands.entity(overlay).despawn_recursive();
+        }
+        return;
+    }
+    if !overlays.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            AutoPauseOverlay,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(OVERLAY_COLOR),
+            GlobalZIndex(i32::MAX - 2),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(strings.get("pause.tap_to_resume")),
+                TextFont {
+                    font: asset_server.load(FONT),
+                    font_size: OVERLAY_FONT_SIZE,
+                    ..default()
+                },
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::ButtonState;
+    use bevy::input::InputPlugin;
+    use bevy::input::mouse::MouseButtonInput;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::i18n::I18nPlugin;
+    use crate::shared_assets::SharedAssetsPlugin;
+
+    #[test]
+    fn pauses_until_tapped() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            SharedAssetsPlugin,
+            AssetPlugin::default(),
+            StatesPlugin,
+            I18nPlugin,
+            InputPlugin,
+            AutoPausePlugin,
+        ))
+        .init_asset::<Font>()
+        .add_event::<WindowFocused>()
+        .add_event::<WindowOccluded>();
+        app.update();
+
+        app.world_mut().send_event(WindowFocused {
+            window: Entity::PLACEHOLDER,
+            focused: false,
+        });
+        app.update();
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+        assert!(app.world().resource::<AutoPause>().is_paused());
+
+        app.world_mut().send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+        assert!(
+            !app.world()
+                .resource::<ButtonInput<MouseButton>>()
+                .just_pressed(MouseButton::Left),
+            "the tap resuming should not reach the bit"
+        );
+    }
+}
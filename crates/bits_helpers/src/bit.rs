@@ -12,23 +12,21 @@ use bevy::render::settings::{WgpuSettings, WgpuSettingsPriority};
 use bevy::window::{WindowMode, WindowResolution};
 use bevy_framepace::{FramepaceSettings, Limiter};
 
+use crate::auto_pause::AutoPausePlugin;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::debug::DebugPlugin;
+use crate::i18n::I18nPlugin;
+use crate::loading::LoadingPlugin;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::ribbit_simulation::RibbitSimulation;
+use crate::shared_assets::SharedAssetsPlugin;
+use crate::storage::StoragePlugin;
+use crate::virtual_resolution::VirtualResolutionPlugin;
 #[cfg(target_arch = "wasm32")]
 use crate::window_resizing::handle_browser_resize;
 use crate::{RibbitCommunicationPlugin, RibbitMessageHandler};
 
-#[cfg(not(target_arch = "wasm32"))]
-pub const FONT: &str = concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/assets/fonts/FiraSans-Bold.ttf"
-);
-#[cfg(target_arch = "wasm32")]
-pub const FONT: &str = concat!(
-    "../../bits_helpers-",
-    env!("CARGO_PKG_VERSION"),
-    "/assets/fonts/FiraSans-Bold.ttf"
-);
+pub const FONT: &str = "bits://fonts/FiraSans-Bold.ttf";
 
 // typical smartphone screen ratio (9:16)
 pub const WINDOW_WIDTH: f32 = 360.0;
@@ -84,6 +82,9 @@ pub fn get_default_app<T: RibbitMessageHandler>(bit_name: &str, bit_version: &st
         ..Default::default()
     };
 
+    // Shared assets are served from their own source, registered before the AssetPlugin
+    app.add_plugins(SharedAssetsPlugin);
+
     app.add_plugins(
         DefaultPlugins
             .set(asset_plugin)
@@ -101,6 +102,21 @@ pub fn get_default_app<T: RibbitMessageHandler>(bit_name: &str, bit_version: &st
 
     app.add_plugins(RibbitCommunicationPlugin::<T>::default());
 
+    // Every bit is laid out in WINDOW_WIDTH x WINDOW_HEIGHT, letterboxed to fit the screen
+    app.add_plugins(VirtualResolutionPlugin::default());
+
+    app.add_plugins(I18nPlugin);
+
+    // Tells the platform the bit is ready once its assets are loaded
+    app.add_plugins(LoadingPlugin);
+
+    // Pauses the bit while the player is away
+    app.add_plugins(AutoPausePlugin);
+
+    app.add_plugins(StoragePlugin {
+        namespace: bit_name.to_string(),
+    });
+
     #[cfg(target_arch = "wasm32")]
     {
         app.add_systems(PreUpdate, handle_browser_resize);
@@ -109,6 +125,7 @@ pub fn get_default_app<T: RibbitMessageHandler>(bit_name: &str, bit_version: &st
     #[cfg(not(target_arch = "wasm32"))]
     {
         app.add_plugins(RibbitSimulation);
+        app.add_plugins(DebugPlugin);
     }
 
     app
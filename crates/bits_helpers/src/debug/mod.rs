@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use thiserror::Error;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod tools;
+#[cfg(not(target_arch = "wasm32"))]
+pub use tools::{DebugPlugin, DebugSettings};
+
+/// Outline drawn around an entity when colliders are shown, in world units
+#[derive(Component, Debug, Clone, Copy)]
+pub enum DebugCollider {
+    Circle(f32),
+    Rectangle(Vec2),
+}
+
+/// Cheats a bit offers to the debug console
+///
+/// Every command is optional, and answers [`DebugCommandError::Unsupported`] unless
+/// implemented:
+///
+/// ```ignore
+/// impl DebugCommands for EmojiCatcher {
+///     fn set_score(world: &mut World, score: i64) -> Result<(), DebugCommandError> {
+///         world.resource_mut::<Score>().0 = score as i32;
+///         Ok(())
+///     }
+/// }
+///
+/// app.debug_commands::<EmojiCatcher>();
+/// ```
+pub trait DebugCommands: 'static {
+    fn jump_to_stage(_world: &mut World, _stage: u32) -> Result<(), DebugCommandError> {
+        Err(DebugCommandError::Unsupported)
+    }
+
+    fn set_score(_world: &mut World, _score: i64) -> Result<(), DebugCommandError> {
+        Err(DebugCommandError::Unsupported)
+    }
+
+    /// Adds time to the bit's clock, removing it when `seconds` is negative
+    fn add_time(_world: &mut World, _seconds: f32) -> Result<(), DebugCommandError> {
+        Err(DebugCommandError::Unsupported)
+    }
+
+    fn win(_world: &mut World) -> Result<(), DebugCommandError> {
+        Err(DebugCommandError::Unsupported)
+    }
+
+    fn lose(_world: &mut World) -> Result<(), DebugCommandError> {
+        Err(DebugCommandError::Unsupported)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DebugCommandError {
+    #[error("Not supported by this bit")]
+    Unsupported,
+    #[error("{0}")]
+    Rejected(String),
+}
+
+/// Registers states and commands with the [`DebugPlugin`]
+///
+/// Registering is harmless without the plugin, and does nothing on wasm where there are
+/// no debug tools, so bits do not need to check the target.
+pub trait DebugAppExt {
+    /// Shows the current value of `S` in the overlay
+    fn debug_state<S: States>(&mut self) -> &mut Self;
+    /// Runs the console commands of `T`
+    fn debug_commands<T: DebugCommands>(&mut self) -> &mut Self;
+}
+
+impl DebugAppExt for App {
+    fn debug_state<S: States>(&mut self) -> &mut Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        tools::register_state::<S>(self);
+        self
+    }
+
+    fn debug_commands<T: DebugCommands>(&mut self) -> &mut Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        tools::register_commands::<T>(self);
+        self
+    }
+}
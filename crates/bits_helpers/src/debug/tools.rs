@@ -0,0 +1,430 @@
+use core::str::FromStr;
+use std::collections::BTreeMap;
+
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::input::InputSystem;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use super::{DebugAppExt, DebugCollider, DebugCommandError, DebugCommands};
+use crate::launch_options::launch_flag;
+use crate::loading::LoadingState;
+
+const OVERLAY_KEY: KeyCode = KeyCode::F3;
+const COLLIDERS_KEY: KeyCode = KeyCode::F4;
+const CONSOLE_KEY: KeyCode = KeyCode::Backquote;
+
+const DEBUG_FONT_SIZE: f32 = 12.0;
+const DEBUG_MARGIN: f32 = 4.0;
+const DEBUG_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+const COLLIDER_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+/// Lines of console output kept on screen
+const CONSOLE_LINES: usize = 6;
+
+const HELP: &str = "stage <n>, score <n>, time <seconds>, win, lose, help";
+
+/// Developer tools for native builds, added by [`get_default_app`](crate::get_default_app)
+///
+/// - `F3` toggles an overlay with the FPS, the entity count and the current value of
+///   the states registered with [`DebugAppExt::debug_state`]
+/// - `F4` toggles the outline of every [`DebugCollider`]
+/// - `` ` `` opens a console running the [`DebugCommands`] of the bit, type `help` to
+///   list them. Keys typed in the console are hidden from the bit.
+///
+/// The overlay is shown from the start with the `debug` launch option.
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+
+        app.insert_resource(DebugSettings {
+            overlay: launch_flag("debug"),
+            colliders: false,
+        })
+        .init_resource::<DebugConsole>()
+        .init_resource::<DebugStates>()
+        .init_resource::<RegisteredDebugCommands>()
+        .debug_state::<LoadingState>()
+        .add_systems(Startup, spawn_debug_ui)
+        .add_systems(
+            PreUpdate,
+            (toggle_debug_tools, edit_console)
+                .chain()
+                .after(InputSystem),
+        )
+        .add_systems(
+            Update,
+            (
+                run_console_commands,
+                update_overlay,
+                update_console,
+                draw_colliders.run_if(|settings: Res<DebugSettings>| settings.colliders),
+            ),
+        );
+    }
+}
+
+/// Which debug tools are shown
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct DebugSettings {
+    pub overlay: bool,
+    pub colliders: bool,
+}
+
+pub(super) fn register_state<S: States>(app: &mut App) {
+    app.init_resource::<DebugStates>()
+        .add_systems(Update, record_state::<S>);
+}
+
+pub(super) fn register_commands<T: DebugCommands>(app: &mut App) {
+    app.insert_resource(RegisteredDebugCommands {
+        jump_to_stage: T::jump_to_stage,
+        set_score: T::set_score,
+        add_time: T::add_time,
+        win: T::win,
+        lose: T::lose,
+    });
+}
+
+/// A console command, parsed from a line such as `stage 3`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DebugCommand {
+    Help,
+    Stage(u32),
+    Score(i64),
+    Time(f32),
+    Win,
+    Lose,
+}
+
+impl DebugCommand {
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let argument = words.next();
+        if words.next().is_some() {
+            return Err(format!("Too many arguments, expected {HELP}"));
+        }
+
+        match (name, argument) {
+            ("help", None) => Ok(Self::Help),
+            ("stage", _) => parse_number(name, argument).map(Self::Stage),
+            ("score", _) => parse_number(name, argument).map(Self::Score),
+            ("time", _) => parse_number(name, argument).and_then(|seconds: f32| {
+                // Bits turn it into a `Duration`, which can't be infinite
+                seconds
+                    .is_finite()
+                    .then_some(Self::Time(seconds))
+                    .ok_or_else(|| format!("`{name}` expects a number"))
+            }),
+            ("win", None) => Ok(Self::Win),
+            ("lose", None) => Ok(Self::Lose),
+            _ => Err(format!("Unknown command `{line}`, expected {HELP}")),
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(name: &str, argument: Option<&str>) -> Result<T, String> {
+    argument
+        .and_then(|argument| argument.parse().ok())
+        .ok_or_else(|| format!("`{name}` expects a number"))
+}
+
+type DebugCommandFn<A> = fn(&mut World, A) -> Result<(), DebugCommandError>;
+
+#[derive(Resource, Clone, Copy)]
+struct RegisteredDebugCommands {
+    jump_to_stage: DebugCommandFn<u32>,
+    set_score: DebugCommandFn<i64>,
+    add_time: DebugCommandFn<f32>,
+    win: fn(&mut World) -> Result<(), DebugCommandError>,
+    lose: fn(&mut World) -> Result<(), DebugCommandError>,
+}
+
+impl Default for RegisteredDebugCommands {
+    fn default() -> Self {
+        struct NoCommands;
+        impl DebugCommands for NoCommands {}
+
+        Self {
+            jump_to_stage: NoCommands::jump_to_stage,
+            set_score: NoCommands::set_score,
+            add_time: NoCommands::add_time,
+            win: NoCommands::win,
+            lose: NoCommands::lose,
+        }
+    }
+}
+
+/// Current value of every registered state, by type name
+#[derive(Resource, Default)]
+struct DebugStates(BTreeMap<&'static str, String>);
+
+#[derive(Resource, Default)]
+struct DebugConsole {
+    open: bool,
+    input: String,
+    submitted: Vec<String>,
+    output: Vec<String>,
+}
+
+impl DebugConsole {
+    fn print(&mut self, line: String) {
+        self.output.push(line);
+        let excess = self.output.len().saturating_sub(CONSOLE_LINES);
+        self.output.drain(..excess);
+    }
+}
+
+#[derive(Component)]
+struct DebugOverlay;
+
+#[derive(Component)]
+struct DebugConsoleText;
+
+fn record_state<S: States>(state: Option<Res<State<S>>>, mut states: ResMut<DebugStates>) {
+    let Some(state) = state else {
+        return;
+    };
+    if state.is_changed() {
+        let name = core::any::type_name::<S>();
+        let name = name.rsplit("::").next().unwrap_or(name);
+        states.0.insert(name, format!("{:?}", state.get()));
+    }
+}
+
+fn debug_text_bundle() -> impl Bundle {
+    (
+        Text::default(),
+        TextFont {
+            font_size: DEBUG_FONT_SIZE,
+            ..default()
+        },
+        BackgroundColor(DEBUG_BACKGROUND),
+        GlobalZIndex(i32::MAX - 1),
+        Visibility::Hidden,
+    )
+}
+
+fn spawn_debug_ui(mut commands: Commands) {
+    commands.spawn((
+        DebugOverlay,
+        debug_text_bundle(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(DEBUG_MARGIN),
+            left: Val::Px(DEBUG_MARGIN),
+            padding: UiRect::all(Val::Px(DEBUG_MARGIN)),
+            ..default()
+        },
+    ));
+    commands.spawn((
+        DebugConsoleText,
+        debug_text_bundle(),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(DEBUG_MARGIN)),
+            ..default()
+        },
+    ));
+}
+
+fn toggle_debug_tools(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<DebugSettings>,
+    mut console: ResMut<DebugConsole>,
+) {
+    if keys.just_pressed(OVERLAY_KEY) {
+        settings.overlay = !settings.overlay;
+    }
+    if keys.just_pressed(COLLIDERS_KEY) {
+        settings.colliders = !settings.colliders;
+    }
+    if keys.just_pressed(CONSOLE_KEY) {
+        console.open = !console.open;
+    }
+}
+
+/// Types into the console while it is open, keeping the keys from the bit
+fn edit_console(
+    mut events: EventReader<KeyboardInput>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut console: ResMut<DebugConsole>,
+) {
+    if !console.open {
+        events.clear();
+        return;
+    }
+
+    for event in events.read() {
+        if !event.state.is_pressed() || event.key_code == CONSOLE_KEY {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(text) => console.input.push_str(text),
+            Key::Space => console.input.push(' '),
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Enter => {
+                let line = core::mem::take(&mut console.input);
+                if !line.trim().is_empty() {
+                    console.submitted.push(line);
+                }
+            }
+            Key::Escape => console.open = false,
+            _ => {}
+        }
+    }
+    keys.reset_all();
+}
+
+fn run_console_commands(world: &mut World) {
+    let submitted = core::mem::take(&mut world.resource_mut::<DebugConsole>().submitted);
+
+    for line in submitted {
+        let output = DebugCommand::parse(&line)
+            .and_then(|command| run_command(world, command).map_err(|error| error.to_string()));
+
+        let mut console = world.resource_mut::<DebugConsole>();
+        console.print(format!("> {line}"));
+        console.print(output.unwrap_or_else(|error| error));
+    }
+}
+
+fn run_command(world: &mut World, command: DebugCommand) -> Result<String, DebugCommandError> {
+    let commands = *world.resource::<RegisteredDebugCommands>();
+    match command {
+        DebugCommand::Help => return Ok(HELP.to_string()),
+        DebugCommand::Stage(stage) => (commands.jump_to_stage)(world, stage),
+        DebugCommand::Score(score) => (commands.set_score)(world, score),
+        DebugCommand::Time(seconds) => (commands.add_time)(world, seconds),
+        DebugCommand::Win => (commands.win)(world),
+        DebugCommand::Lose => (commands.lose)(world),
+    }?;
+    Ok("Ok".to_string())
+}
+
+fn update_overlay(
+    settings: Res<DebugSettings>,
+    diagnostics: Res<DiagnosticsStore>,
+    states: Res<DebugStates>,
+    mut overlays: Query<(&mut Text, &mut Visibility), With<DebugOverlay>>,
+) {
+    for (mut text, mut visibility) in &mut overlays {
+        *visibility = if settings.overlay {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if !settings.overlay {
+            continue;
+        }
+
+        let fps = diagnostics
+            .get(&FrameTimeDiagnosticsPlugin::FPS)
+            .and_then(Diagnostic::smoothed)
+            .unwrap_or_default();
+        let entities = diagnostics
+            .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+            .and_then(Diagnostic::value)
+            .unwrap_or_default();
+
+        let mut lines = vec![format!("FPS {fps:.0}"), format!("Entities {entities:.0}")];
+        lines.extend(
+            states
+                .0
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}")),
+        );
+        text.0 = lines.join("\n");
+    }
+}
+
+fn update_console(
+    console: Res<DebugConsole>,
+    mut texts: Query<(&mut Text, &mut Visibility), With<DebugConsoleText>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+
+    for (mut text, mut visibility) in &mut texts {
+        *visibility = if console.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        let mut lines = console.output.clone();
+        lines.push(format!("> {}_", console.input));
+        text.0 = lines.join("\n");
+    }
+}
+
+fn draw_colliders(colliders: Query<(&DebugCollider, &GlobalTransform)>, mut gizmos: Gizmos) {
+    for (collider, transform) in &colliders {
+        let position = transform.translation().truncate();
+        match *collider {
+            DebugCollider::Circle(radius) => {
+                gizmos.circle_2d(position, radius, COLLIDER_COLOR);
+            }
+            DebugCollider::Rectangle(size) => {
+                gizmos.rect_2d(position, size, COLLIDER_COLOR);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(DebugCommand::parse("stage 3"), Ok(DebugCommand::Stage(3)));
+        assert_eq!(
+            DebugCommand::parse("score -20"),
+            Ok(DebugCommand::Score(-20))
+        );
+        assert_eq!(DebugCommand::parse("  win "), Ok(DebugCommand::Win));
+        assert!(
+            matches!(DebugCommand::parse("time 2.5"), Ok(DebugCommand::Time(seconds)) if (seconds - 2.5).abs() < f32::EPSILON),
+            "time takes seconds"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_commands() {
+        assert!(
+            DebugCommand::parse("stage").is_err(),
+            "stage needs a number"
+        );
+        assert!(
+            DebugCommand::parse("stage two").is_err(),
+            "stage needs a number"
+        );
+        assert!(
+            DebugCommand::parse("time inf").is_err(),
+            "time needs a finite number"
+        );
+        assert!(
+            DebugCommand::parse("win now").is_err(),
+            "win takes no argument"
+        );
+        assert!(
+            DebugCommand::parse("fly").is_err(),
+            "unknown commands are rejected"
+        );
+    }
+}
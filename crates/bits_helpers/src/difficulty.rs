@@ -0,0 +1,439 @@
+use core::time::Duration;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Loads a bit's difficulty curves, so designers can tune them without recompiling
+///
+/// Curves are read from a `.difficulty.ron` file in the bit's assets:
+///
+/// ```ron
+/// (
+///     curves: {
+///         "emoji_speed": (shape: Exponential(start: 100, factor: 1.2)),
+///         "total_emojis": (shape: Linear(start: 30, rate: 5)),
+///         "time_limit": (shape: Exponential(start: 20, factor: 0.9), min: 5),
+///     },
+/// )
+/// ```
+///
+/// The same file is embedded as `defaults`, which are used until the asset is loaded
+/// and for curves the asset does not define.
+pub struct DifficultyPlugin {
+    pub path: &'static str,
+    pub defaults: &'static str,
+}
+
+impl DifficultyPlugin {
+    pub const fn new(path: &'static str, defaults: &'static str) -> Self {
+        Self { path, defaults }
+    }
+}
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        let defaults = DifficultyTable::from_ron(self.defaults).unwrap_or_else(|error| {
+            error!("Invalid default difficulty curves: {error}");
+            DifficultyTable::default()
+        });
+
+        app.init_asset::<DifficultyTable>()
+            .init_asset_loader::<DifficultyTableLoader>();
+
+        let handle = app.world().resource::<AssetServer>().load(self.path);
+        app.insert_resource(DifficultyCurves { defaults, handle });
+    }
+}
+
+/// How a curve grows along its axis, `x` being 0 at the first stage or at the start
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CurveShape {
+    Constant(f32),
+    /// `start + rate * x`
+    Linear {
+        start: f32,
+        rate: f32,
+    },
+    /// `start * factor ^ x`
+    Exponential {
+        start: f32,
+        factor: f32,
+    },
+    /// `start + step * floor((x + offset) / every)`, never fewer than zero steps
+    Stepped {
+        start: f32,
+        step: f32,
+        every: f32,
+        #[serde(default)]
+        offset: f32,
+    },
+    /// Interpolates linearly between `(x, value)` keyframes in increasing `x` order,
+    /// holding the first and last values outside of them
+    Keyframes(Vec<(f32, f32)>),
+}
+
+/// A difficulty parameter as a function of the stage or of the time played
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyCurve {
+    pub shape: CurveShape,
+    #[serde(default = "unbounded_min")]
+    pub min: f32,
+    #[serde(default = "unbounded_max")]
+    pub max: f32,
+}
+
+const fn unbounded_min() -> f32 {
+    f32::NEG_INFINITY
+}
+
+const fn unbounded_max() -> f32 {
+    f32::INFINITY
+}
+
+impl From<CurveShape> for DifficultyCurve {
+    fn from(shape: CurveShape) -> Self {
+        Self {
+            shape,
+            min: unbounded_min(),
+            max: unbounded_max(),
+        }
+    }
+}
+
+impl DifficultyCurve {
+    #[must_use]
+    pub const fn clamped(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    pub fn sample(&self, x: f32) -> f32 {
+        let value = match &self.shape {
+            CurveShape::Constant(value) => *value,
+            CurveShape::Linear { start, rate } => rate.mul_add(x, *start),
+            CurveShape::Exponential { start, factor } => start * factor.powf(x),
+            CurveShape::Stepped {
+                start,
+                step,
+                every,
+                offset,
+            } => {
+                let steps = if *every > 0.0 {
+                    ((x + offset) / every).floor().max(0.0)
+                } else {
+                    0.0
+                };
+                step.mul_add(steps, *start)
+            }
+            CurveShape::Keyframes(keyframes) => sample_keyframes(keyframes, x),
+        };
+
+        // Unlike `clamp`, never panics on bounds a designer got wrong
+        value.max(self.min).min(self.max)
+    }
+
+    /// Whether the bounds are ordered, every value is a number and keyframes are in
+    /// strictly increasing key order, infinite bounds meaning unbounded
+    pub fn is_valid(&self) -> bool {
+        let values_finite = match &self.shape {
+            CurveShape::Constant(value) => value.is_finite(),
+            CurveShape::Linear { start, rate } => start.is_finite() && rate.is_finite(),
+            CurveShape::Exponential { start, factor } => start.is_finite() && factor.is_finite(),
+            CurveShape::Stepped {
+                start,
+                step,
+                every,
+                offset,
+            } => [start, step, every, offset]
+                .iter()
+                .all(|value| value.is_finite()),
+            CurveShape::Keyframes(keyframes) => {
+                keyframes
+                    .iter()
+                    .all(|(key, value)| key.is_finite() && value.is_finite())
+                    && keyframes.windows(2).all(|pair| match pair {
+                        [(previous, _), (next, _)] => previous < next,
+                        _ => true,
+                    })
+            }
+        };
+        values_finite && self.min <= self.max
+    }
+
+    /// Samples the curve at a 1-based stage number, the first stage getting the start value
+    pub fn at_stage(&self, stage: u32) -> f32 {
+        self.sample(stage.saturating_sub(1) as f32)
+    }
+
+    /// Samples the curve at a time played, in seconds
+    pub fn at_time(&self, elapsed: Duration) -> f32 {
+        self.sample(elapsed.as_secs_f32())
+    }
+}
+
+fn sample_keyframes(keyframes: &[(f32, f32)], x: f32) -> f32 {
+    let after = keyframes.iter().position(|&(key, _)| key > x);
+    match after {
+        None => keyframes.last().map_or(0.0, |&(_, value)| value),
+        Some(0) => keyframes.first().map_or(0.0, |&(_, value)| value),
+        Some(index) => {
+            let (Some(&(x0, y0)), Some(&(x1, y1))) =
+                (keyframes.get(index - 1), keyframes.get(index))
+            else {
+                return 0.0;
+            };
+            y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+        }
+    }
+}
+
+/// Named difficulty curves of a bit
+#[derive(Asset, TypePath, Debug, Clone, Default, Deserialize)]
+pub struct DifficultyTable {
+    curves: HashMap<String, DifficultyCurve>,
+}
+
+impl DifficultyTable {
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::de::from_str(ron).map(Self::without_invalid_curves)
+    }
+
+    /// Drops the curves [`DifficultyCurve::is_valid`] rejects, so the defaults are used
+    /// for them instead
+    fn without_invalid_curves(mut self) -> Self {
+        self.curves.retain(|name, curve| {
+            let valid = curve.is_valid();
+            if !valid {
+                warn!(
+                    "Ignored difficulty curve {name}, its bounds, values or keys are invalid: {curve:?}"
+                );
+            }
+            valid
+        });
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DifficultyCurve> {
+        self.curves.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.curves.keys().map(String::as_str)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DifficultyTableLoaderError {
+    #[error("Could not read difficulty curves: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse difficulty curves: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[derive(Default)]
+pub struct DifficultyTableLoader;
+
+impl AssetLoader for DifficultyTableLoader {
+    type Asset = DifficultyTable;
+    type Settings = ();
+    type Error = DifficultyTableLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let table: DifficultyTable = ron::de::from_bytes(&bytes)?;
+        Ok(table.without_invalid_curves())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["difficulty.ron"]
+    }
+}
+
+#[derive(Resource)]
+pub struct DifficultyCurves {
+    defaults: DifficultyTable,
+    handle: Handle<DifficultyTable>,
+}
+
+/// Samples the bit's difficulty curves by name
+#[derive(SystemParam)]
+pub struct Difficulty<'w> {
+    curves: Res<'w, DifficultyCurves>,
+    tables: Res<'w, Assets<DifficultyTable>>,
+}
+
+impl Difficulty<'_> {
+    /// The curve from the loaded asset, or from the embedded defaults
+    pub fn curve(&self, name: &str) -> Option<&DifficultyCurve> {
+        self.tables
+            .get(&self.curves.handle)
+            .and_then(|table| table.get(name))
+            .or_else(|| self.curves.defaults.get(name))
+    }
+
+    fn sample_with(&self, name: &str, sample: impl FnOnce(&DifficultyCurve) -> f32) -> f32 {
+        self.curve(name).map_or_else(
+            || {
+                error!("Unknown difficulty curve {name}");
+                0.0
+            },
+            sample,
+        )
+    }
+
+    pub fn sample(&self, name: &str, x: f32) -> f32 {
+        self.sample_with(name, |curve| curve.sample(x))
+    }
+
+    pub fn at_stage(&self, name: &str, stage: u32) -> f32 {
+        self.sample_with(name, |curve| curve.at_stage(stage))
+    }
+
+    pub fn at_time(&self, name: &str, elapsed: Duration) -> f32 {
+        self.sample_with(name, |curve| curve.at_time(elapsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_samples(curve: &DifficultyCurve, expected: &[(f32, f32)]) {
+        for &(x, value) in expected {
+            let sampled = curve.sample(x);
+            assert!(
+                (sampled - value).abs() < 1e-4,
+                "{curve:?} at {x} is {sampled}, expected {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn samples_shapes() {
+        let linear = DifficultyCurve::from(CurveShape::Linear {
+            start: 100.0,
+            rate: 10.0,
+        })
+        .clamped(0.0, 400.0);
+        assert_samples(&linear, &[(0.0, 100.0), (2.5, 125.0), (60.0, 400.0)]);
+
+        let exponential = DifficultyCurve::from(CurveShape::Exponential {
+            start: 20.0,
+            factor: 0.9,
+        });
+        assert_samples(&exponential, &[(0.0, 20.0), (1.0, 18.0), (2.0, 16.2)]);
+
+        let stepped = DifficultyCurve::from(CurveShape::Stepped {
+            start: 6.0,
+            step: 2.0,
+            every: 2.0,
+            offset: 1.0,
+        });
+        assert_samples(&stepped, &[(0.0, 6.0), (1.0, 8.0), (2.0, 8.0), (3.0, 10.0)]);
+
+        let keyframes = DifficultyCurve::from(CurveShape::Keyframes(vec![
+            (0.0, 1.0),
+            (10.0, 2.0),
+            (20.0, 0.0),
+        ]));
+        assert_samples(
+            &keyframes,
+            &[
+                (-5.0, 1.0),
+                (5.0, 1.5),
+                (10.0, 2.0),
+                (15.0, 1.0),
+                (30.0, 0.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn first_stage_gets_the_start_value() {
+        let curve = DifficultyCurve::from(CurveShape::Linear {
+            start: 30.0,
+            rate: 5.0,
+        });
+        assert!(
+            (curve.at_stage(1) - 30.0).abs() < f32::EPSILON,
+            "stage 1 should be the start"
+        );
+        assert!(
+            (curve.at_stage(3) - 40.0).abs() < f32::EPSILON,
+            "stage 3 should be two steps in"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_curves() {
+        let reversed = DifficultyCurve::from(CurveShape::Constant(3.0)).clamped(5.0, 2.0);
+        assert!(!reversed.is_valid(), "min above max is invalid");
+        assert!(
+            reversed.sample(0.0).is_finite(),
+            "sampling should not panic"
+        );
+        assert!(
+            !DifficultyCurve::from(CurveShape::Constant(1.0))
+                .clamped(f32::NAN, 2.0)
+                .is_valid(),
+            "NaN bounds are invalid"
+        );
+        assert!(
+            !DifficultyCurve::from(CurveShape::Keyframes(vec![(10.0, 1.0), (0.0, 2.0)])).is_valid(),
+            "keyframes out of order are invalid"
+        );
+        assert!(
+            !DifficultyCurve::from(CurveShape::Keyframes(vec![(0.0, 1.0), (0.0, 2.0)])).is_valid(),
+            "keyframes sharing a key are invalid"
+        );
+
+        let table = DifficultyTable::from_ron(
+            r#"(
+                curves: {
+                    "speed": (shape: Constant(1), min: 5, max: 2),
+                    "spawn": (shape: Keyframes([(30, 0.5), (0, 1.5)])),
+                    "cards": (shape: Constant(6)),
+                },
+            )"#,
+        )
+        .unwrap();
+        assert!(table.get("speed").is_none(), "invalid curves are dropped");
+        assert!(
+            table.get("spawn").is_none(),
+            "unordered keyframes are dropped"
+        );
+        assert!(table.get("cards").is_some(), "valid curves are kept");
+    }
+
+    #[test]
+    fn parses_ron() {
+        let table = DifficultyTable::from_ron(
+            r#"(
+                curves: {
+                    "speed": (shape: Exponential(start: 100, factor: 1.2)),
+                    "cards": (shape: Stepped(start: 6, step: 2, every: 2), max: 24),
+                    "spawn": (shape: Keyframes([(0, 1.5), (30, 0.5)])),
+                },
+            )"#,
+        )
+        .unwrap();
+
+        let cards = table.get("cards").unwrap();
+        assert_samples(cards, &[(0.0, 6.0), (2.0, 8.0), (100.0, 24.0)]);
+        assert!(cards.min.is_infinite(), "min should default to unbounded");
+        assert_samples(table.get("spawn").unwrap(), &[(15.0, 1.0)]);
+        assert!(table.get("missing").is_none(), "unknown curves are absent");
+    }
+}
@@ -0,0 +1,848 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy::utils::default;
+use bevy::window::PrimaryWindow;
+use thiserror::Error;
+
+mod registry;
+pub use registry::*;
+mod shape;
+pub use shape::*;
+mod similarity;
+pub use similarity::*;
+pub mod variants;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+enum EmojiSystemSet {
+    Load,
+    Analyze,
+    Validate,
+}
+
+pub struct EmojiPlugin;
+
+impl Plugin for EmojiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AtlasValidation>()
+            .init_resource::<EmojiAtlases>()
+            .init_resource::<variants::EmojiVariantCache>()
+            .configure_sets(
+                Update,
+                (
+                    EmojiSystemSet::Load,
+                    EmojiSystemSet::Analyze.after(EmojiSystemSet::Load),
+                    EmojiSystemSet::Validate.after(EmojiSystemSet::Analyze),
+                ),
+            )
+            .add_systems(Startup, setup_emoji_atlas)
+            .add_systems(
+                Update,
+                load_requested_atlases
+                    .run_if(registry::has_requested_atlases)
+                    .in_set(EmojiSystemSet::Load),
+            )
+            .add_systems(
+                Update,
+                (
+                    analyze_emoji_atlas.run_if(default_atlas_pending),
+                    analyze_registered_atlases.run_if(registry::has_pending_atlases),
+                )
+                    .in_set(EmojiSystemSet::Analyze),
+            )
+            .add_systems(
+                Update,
+                (
+                    validate_emoji_atlas.run_if(default_atlas_pending),
+                    validate_registered_atlases.run_if(registry::has_pending_atlases),
+                )
+                    .in_set(EmojiSystemSet::Validate),
+            );
+    }
+}
+
+/// Size of the default emoji atlas texture
+pub const ATLAS_SIZE: UVec2 = UVec2::new(4096, 4096);
+/// Size of a cell in the default emoji atlas
+pub const EMOJI_SIZE: UVec2 = UVec2::new(64, 64);
+
+pub const ATLAS_PATH: &str = "bits://emojis/EmojiAtlas.ktx2";
+
+/// Atlas cells analyzed each frame while loading, a sixteenth of the default atlas
+pub const ANALYZED_CELLS_PER_FRAME: usize = 256;
+
+#[derive(Error, Debug)]
+pub enum AtlasError {
+    #[error("Failed to load atlas texture: {0}")]
+    TextureLoadError(String),
+
+    #[error("Atlas dimensions mismatch - expected {expected:?}, got {actual:?}")]
+    DimensionMismatch { expected: UVec2, actual: UVec2 },
+
+    #[error("No valid emoji cells found in atlas")]
+    NoValidCells,
+
+    #[error("Invalid texture format: {0:?}")]
+    InvalidFormat(TextureFormat),
+
+    #[error("Emoji atlas is not loaded yet")]
+    NotReady,
+
+    #[error("Emoji index {0} is not a valid atlas cell")]
+    InvalidIndex(usize),
+
+    #[error("Requested {requested} emojis but only {available} are available")]
+    NotEnoughEmojis { requested: usize, available: usize },
+
+    #[error("Emoji {0} has no opaque pixels")]
+    NoShape(usize),
+
+    #[error("No emoji atlas registered as {0:?}")]
+    UnknownAtlas(AtlasId),
+}
+
+/// Descriptor of the default emoji atlas, always loaded at startup
+pub fn default_atlas_descriptor() -> AtlasDescriptor {
+    AtlasDescriptor::new(ATLAS_PATH, EMOJI_SIZE, ATLAS_SIZE / EMOJI_SIZE).preloaded()
+}
+
+/// A loaded atlas and the per-cell data derived from its pixels
+///
+/// The resource holds the default emoji atlas; other atlases live in [`EmojiAtlases`].
+#[derive(Resource)]
+pub struct EmojiAtlas {
+    texture: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    cell_size: UVec2,
+    grid: UVec2,
+    valid_indices: Vec<usize>,
+    /// Per-cell validity, indexed by atlas index, for O(1) lookups
+    valid_cells: Vec<bool>,
+    /// Per-cell alpha shapes, indexed by atlas index
+    shapes: Vec<Option<EmojiShape>>,
+    /// Per-cell visual descriptors, indexed by atlas index
+    features: Vec<Option<EmojiFeatures>>,
+}
+
+impl EmojiAtlas {
+    const fn new(
+        texture: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        cell_size: UVec2,
+        grid: UVec2,
+    ) -> Self {
+        Self {
+            texture,
+            layout,
+            cell_size,
+            grid,
+            valid_indices: Vec::new(),
+            valid_cells: Vec::new(),
+            shapes: Vec::new(),
+            features: Vec::new(),
+        }
+    }
+
+    /// Size in pixels of a single cell
+    pub const fn cell_size(&self) -> UVec2 {
+        self.cell_size
+    }
+
+    /// Number of columns and rows of cells
+    pub const fn grid(&self) -> UVec2 {
+        self.grid
+    }
+
+    const fn cell_count(&self) -> usize {
+        (self.grid.x * self.grid.y) as usize
+    }
+
+    fn is_valid_index(&self, index: usize) -> bool {
+        self.valid_cells.get(index).copied().unwrap_or(false)
+    }
+
+    fn sprite(&self, index: usize) -> Sprite {
+        Sprite {
+            image: self.texture.clone(),
+            texture_atlas: Some(TextureAtlas {
+                layout: self.layout.clone(),
+                index,
+            }),
+            ..default()
+        }
+    }
+
+    fn image_node(&self, index: usize) -> ImageNode {
+        ImageNode::from_atlas_image(
+            self.texture.clone(),
+            TextureAtlas {
+                layout: self.layout.clone(),
+                index,
+            },
+        )
+    }
+}
+
+#[derive(Component)]
+pub struct EmojiSprite;
+
+/// Marker for emojis rendered as Bevy UI nodes rather than world sprites
+#[derive(Component)]
+pub struct EmojiNode;
+
+#[derive(Resource, Default)]
+pub struct AtlasValidation {
+    is_analyzed: bool,
+    is_loaded: bool,
+    is_failed: bool,
+    total_emojis: usize,
+}
+
+impl AtlasValidation {
+    /// Returns whether the default atlas is loaded, analyzed and validated
+    pub const fn is_loaded(&self) -> bool {
+        self.is_loaded
+    }
+
+    /// Returns whether the atlas will never load, its texture missing or unusable
+    pub const fn is_failed(&self) -> bool {
+        self.is_failed
+    }
+
+    /// Returns whether the atlas is still being loaded, analyzed or validated
+    const fn is_pending(&self) -> bool {
+        !self.is_loaded && !self.is_failed
+    }
+}
+
+fn setup_emoji_atlas(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Some(atlas) = default_atlas_descriptor().load(
+        registry::scale_factor(&windows),
+        &asset_server,
+        &mut texture_atlas_layouts,
+    ) else {
+        error!("Default emoji atlas has no texture source");
+        return;
+    };
+
+    commands.insert_resource(atlas);
+}
+
+/// Run condition keeping the default atlas systems from taking it mutably once it's done
+fn default_atlas_pending(atlas: Option<Res<EmojiAtlas>>, validation: Res<AtlasValidation>) -> bool {
+    atlas.is_some() && validation.is_pending()
+}
+
+fn analyze_emoji_atlas(
+    mut atlas: ResMut<EmojiAtlas>,
+    mut validation: ResMut<AtlasValidation>,
+    images: Res<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+) {
+    atlas.check_texture(&mut validation, &asset_server);
+    atlas.analyze(&mut validation, &images);
+}
+
+fn validate_emoji_atlas(
+    atlas: Res<EmojiAtlas>,
+    mut validation: ResMut<AtlasValidation>,
+    atlas_images: Res<Assets<Image>>,
+) {
+    atlas.validate(&mut validation, &atlas_images);
+}
+
+impl EmojiAtlas {
+    /// Gives up on the atlas if its texture failed to load, the asset server logging why
+    fn check_texture(&self, validation: &mut AtlasValidation, asset_server: &AssetServer) {
+        if asset_server.load_state(&self.texture).is_failed() {
+            validation.is_failed = true;
+        }
+    }
+
+    /// Finds the non-empty cells and derives their shapes and features, once the texture is loaded
+    ///
+    /// Only [`ANALYZED_CELLS_PER_FRAME`] cells are analyzed per call, so a large atlas
+    /// doesn't freeze the loading screen.
+    fn analyze(&mut self, validation: &mut AtlasValidation, images: &Assets<Image>) {
+        if validation.is_analyzed || validation.is_failed {
+            return;
+        }
+
+        let Some(texture) = images.get(&self.texture) else {
+            return;
+        };
+
+        let cells = self.cell_count();
+        let start = self.shapes.len();
+        let end = (start + ANALYZED_CELLS_PER_FRAME).min(cells);
+
+        for index in start..end {
+            let pixels = match self.read_cell(texture, index) {
+                Ok(pixels) => pixels,
+                Err(err) => {
+                    error!("{err}");
+                    validation.is_failed = true;
+                    return;
+                }
+            };
+
+            if pixels
+                .iter()
+                .any(|pixel| pixel.iter().any(|&channel| channel > 0))
+            {
+                self.valid_indices.push(index);
+                self.shapes
+                    .push(EmojiShape::from_pixels(&pixels, self.cell_size));
+                self.features
+                    .push(Some(EmojiFeatures::from_pixels(&pixels, self.cell_size)));
+            } else {
+                self.shapes.push(None);
+                self.features.push(None);
+            }
+        }
+
+        if end < cells {
+            return;
+        }
+
+        if self.valid_indices.is_empty() {
+            error!("No valid emoji cells found in atlas!");
+            validation.is_failed = true;
+            return;
+        }
+
+        let mut valid_cells = vec![false; cells];
+        for &index in &self.valid_indices {
+            if let Some(cell) = valid_cells.get_mut(index) {
+                *cell = true;
+            }
+        }
+
+        self.valid_cells = valid_cells;
+        validation.is_analyzed = true;
+        info!(
+            "Atlas analyzed: found {} valid emoji cells",
+            self.valid_indices.len()
+        );
+    }
+
+    /// Marks the atlas as ready once its texture has the expected size and it is analyzed
+    fn validate(&self, validation: &mut AtlasValidation, images: &Assets<Image>) {
+        if validation.is_loaded || validation.is_failed {
+            return;
+        }
+
+        let Some(texture) = images.get(&self.texture) else {
+            return;
+        };
+
+        let expected = self.cell_size * self.grid;
+        if texture.width() != expected.x || texture.height() != expected.y {
+            error!(
+                "Emoji atlas dimensions mismatch! Expected {}x{}, got {}x{}",
+                expected.x,
+                expected.y,
+                texture.width(),
+                texture.height()
+            );
+            validation.is_failed = true;
+            return;
+        }
+
+        if !validation.is_analyzed {
+            return;
+        }
+
+        validation.is_loaded = true;
+        validation.total_emojis = self.valid_indices.len();
+        info!(
+            "Emoji atlas validated! Total positions: {}",
+            validation.total_emojis
+        );
+    }
+
+    /// Reads the pixels of a cell as RGBA, row-major from the top left
+    fn read_cell(&self, image: &Image, index: usize) -> Result<Vec<[u8; 4]>, AtlasError> {
+        let format = image.texture_descriptor.format;
+        let swap_red_blue = match format {
+            TextureFormat::Rgba8UnormSrgb | TextureFormat::Rgba8Unorm => false,
+            TextureFormat::Bgra8UnormSrgb => true,
+            format => return Err(AtlasError::InvalidFormat(format)),
+        };
+
+        let cols = self.grid.x.max(1) as usize;
+        let cell_x = (index % cols) as u32 * self.cell_size.x;
+        let cell_y = (index / cols) as u32 * self.cell_size.y;
+        let mut pixels = Vec::with_capacity((self.cell_size.x * self.cell_size.y) as usize);
+
+        for y in 0..self.cell_size.y {
+            for x in 0..self.cell_size.x {
+                let pixel_x = cell_x + x;
+                let pixel_y = cell_y + y;
+                let idx = ((pixel_y * image.width() + pixel_x) * 4) as usize;
+
+                let pixel = if pixel_x < image.width() && pixel_y < image.height() {
+                    image.data.get(idx..idx + 4)
+                } else {
+                    None
+                };
+
+                pixels.push(match pixel {
+                    Some(&[b, g, r, a]) if swap_red_blue => [r, g, b, a],
+                    Some(&[r, g, b, a]) => [r, g, b, a],
+                    _ => [0; 4],
+                });
+            }
+        }
+
+        Ok(pixels)
+    }
+
+    /// Picks `count` distinct random valid cells
+    fn pick_random(&self, count: usize) -> Result<Vec<usize>, AtlasError> {
+        let available = self.valid_indices.len();
+        if count > available {
+            return Err(AtlasError::NotEnoughEmojis {
+                requested: count,
+                available,
+            });
+        }
+
+        let mut indices = self.valid_indices.clone();
+        let mut result = Vec::with_capacity(count);
+        while result.len() < count {
+            let idx = fastrand::usize(..indices.len());
+            result.push(indices.swap_remove(idx));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Creates a new emoji sprite entity with the specified transform
+///
+/// # Parameters
+/// * `commands` - Mutable commands resource for entity spawning
+/// * `atlas` - Reference to the emoji atlas resource
+/// * `validation` - Reference to the atlas validation state
+/// * `index` - The index of the emoji in the atlas
+/// * `transform` - The transform defining the emoji's position, rotation, and scale
+///
+/// # Returns
+/// * `Option<Entity>` - The spawned entity ID if successful, None if the emoji index is invalid
+pub fn spawn_emoji(
+    commands: &mut Commands,
+    atlas: &Res<EmojiAtlas>,
+    validation: &Res<AtlasValidation>,
+    index: usize,
+    transform: Transform,
+) -> Option<Entity> {
+    if !validation.is_loaded || !atlas.is_valid_index(index) {
+        return None;
+    }
+
+    Some(
+        commands
+            .spawn((
+                atlas.sprite(index),
+                transform,
+                Visibility::Visible,
+                EmojiSprite,
+            ))
+            .id(),
+    )
+}
+
+/// Gets a random selection of emoji indices suitable for creating pairs
+pub fn get_random_emojis(
+    atlas: &Res<EmojiAtlas>,
+    validation: &Res<AtlasValidation>,
+    count: usize,
+) -> Vec<usize> {
+    if !validation.is_loaded {
+        return Vec::new();
+    }
+
+    let valid_indices = &atlas.valid_indices;
+    let mut indices: Vec<usize> = valid_indices.clone();
+    let mut result = Vec::with_capacity(count);
+
+    // Take random indices until we have enough or run out
+    while result.len() < count && !indices.is_empty() {
+        let idx = fastrand::usize(..indices.len());
+        result.push(indices.swap_remove(idx));
+    }
+
+    result
+}
+
+/// Returns whether the emoji system is ready for use
+#[must_use]
+pub fn is_emoji_system_ready(validation: &Res<AtlasValidation>) -> bool {
+    validation.is_loaded
+}
+
+/// Gets the total number of valid emojis available
+#[must_use]
+pub fn get_emoji_count(validation: &Res<AtlasValidation>) -> usize {
+    validation.total_emojis
+}
+
+/// Returns whether the index is valid
+#[must_use]
+pub fn is_valid_emoji_index(atlas: &Res<EmojiAtlas>, index: usize) -> bool {
+    atlas.is_valid_index(index)
+}
+
+/// System parameter giving access to the default emoji atlas and every registered atlas
+///
+/// Prefer this over passing `Res<EmojiAtlas>` and `Res<AtlasValidation>` separately.
+/// Methods taking `impl Into<EmojiRef>` accept plain indices into the default atlas.
+#[derive(SystemParam)]
+pub struct Emojis<'w> {
+    atlas: Res<'w, EmojiAtlas>,
+    validation: Res<'w, AtlasValidation>,
+    atlases: Res<'w, EmojiAtlases>,
+}
+
+impl Emojis<'_> {
+    /// Returns whether the default atlas is loaded, analyzed and validated
+    pub fn is_ready(&self) -> bool {
+        self.validation.is_loaded()
+    }
+
+    /// Returns whether an atlas is ready, requesting it if it was not loaded yet
+    pub fn is_atlas_ready(&self, atlas: AtlasId) -> bool {
+        self.ready_atlas(atlas).is_ok()
+    }
+
+    /// Gets the total number of valid emojis available in the default atlas
+    pub fn count(&self) -> usize {
+        self.validation.total_emojis
+    }
+
+    /// Returns whether the emoji points to a non-empty atlas cell
+    pub fn is_valid(&self, emoji: impl Into<EmojiRef>) -> bool {
+        let emoji = emoji.into();
+        self.lookup(emoji.atlas)
+            .is_ok_and(|(atlas, _)| atlas.is_valid_index(emoji.index))
+    }
+
+    /// Checks that the emoji's atlas is ready and that the emoji can be displayed
+    pub fn validate(&self, emoji: impl Into<EmojiRef>) -> Result<EmojiRef, AtlasError> {
+        let emoji = emoji.into();
+        self.resolve(emoji).map(|_| emoji)
+    }
+
+    /// Finds which atlas cell a sprite displays, if it displays an emoji
+    pub fn sprite_ref(&self, sprite: &Sprite) -> Option<EmojiRef> {
+        let index = sprite.texture_atlas.as_ref()?.index;
+        if sprite.image == self.atlas.texture {
+            return Some(EmojiRef::from(index));
+        }
+
+        self.atlases
+            .loaded()
+            .find(|(_, atlas)| atlas.texture == sprite.image)
+            .map(|(id, _)| EmojiRef::new(id, index))
+    }
+
+    /// Gets an atlas and its validation state, requesting registered atlases on first use
+    fn lookup(&self, id: AtlasId) -> Result<(&EmojiAtlas, &AtlasValidation), AtlasError> {
+        if id == AtlasId::DEFAULT {
+            return Ok((&self.atlas, &self.validation));
+        }
+
+        let registered = self.atlases.get(id).ok_or(AtlasError::UnknownAtlas(id))?;
+        registered.atlas.as_ref().map_or_else(
+            || {
+                self.atlases.request(id);
+                Err(AtlasError::NotReady)
+            },
+            |atlas| Ok((atlas, &registered.validation)),
+        )
+    }
+
+    fn ready_atlas(&self, id: AtlasId) -> Result<&EmojiAtlas, AtlasError> {
+        match self.lookup(id)? {
+            (atlas, validation) if validation.is_loaded => Ok(atlas),
+            _ => Err(AtlasError::NotReady),
+        }
+    }
+
+    /// Gets the atlas of a valid emoji along with its index in that atlas
+    fn resolve(&self, emoji: impl Into<EmojiRef>) -> Result<(&EmojiAtlas, usize), AtlasError> {
+        let emoji = emoji.into();
+        let atlas = self.ready_atlas(emoji.atlas)?;
+        if !atlas.is_valid_index(emoji.index) {
+            return Err(AtlasError::InvalidIndex(emoji.index));
+        }
+        Ok((atlas, emoji.index))
+    }
+
+    /// Builds a sprite displaying the emoji at its native atlas size
+    pub fn sprite(&self, emoji: impl Into<EmojiRef>) -> Result<Sprite, AtlasError> {
+        self.resolve(emoji)
+            .map(|(atlas, index)| atlas.sprite(index))
+    }
+
+    /// Builds a sprite displaying the emoji at the given size in world units
+    pub fn sprite_sized(
+        &self,
+        emoji: impl Into<EmojiRef>,
+        size: Vec2,
+    ) -> Result<Sprite, AtlasError> {
+        let mut sprite = self.sprite(emoji)?;
+        sprite.custom_size = Some(size);
+        Ok(sprite)
+    }
+
+    /// Spawns an emoji sprite entity at its native atlas size
+    pub fn spawn(
+        &self,
+        commands: &mut Commands,
+        emoji: impl Into<EmojiRef>,
+        transform: Transform,
+    ) -> Result<Entity, AtlasError> {
+        let sprite = self.sprite(emoji)?;
+        Ok(commands
+            .spawn((sprite, transform, Visibility::Visible, EmojiSprite))
+            .id())
+    }
+
+    /// Spawns an emoji sprite entity rendered at `size` world units,
+    /// without having to derive a scale from the atlas cell size
+    pub fn spawn_sized(
+        &self,
+        commands: &mut Commands,
+        emoji: impl Into<EmojiRef>,
+        size: Vec2,
+        transform: Transform,
+    ) -> Result<Entity, AtlasError> {
+        let sprite = self.sprite_sized(emoji, size)?;
+        Ok(commands
+            .spawn((sprite, transform, Visibility::Visible, EmojiSprite))
+            .id())
+    }
+
+    /// Builds an image node displaying the emoji inside the UI hierarchy
+    pub fn image_node(&self, emoji: impl Into<EmojiRef>) -> Result<ImageNode, AtlasError> {
+        self.resolve(emoji)
+            .map(|(atlas, index)| atlas.image_node(index))
+    }
+
+    /// Builds a square UI emoji of `size` logical pixels, following UI layout and scaling
+    pub fn ui_node(
+        &self,
+        emoji: impl Into<EmojiRef>,
+        size: f32,
+    ) -> Result<impl Bundle, AtlasError> {
+        let image = self.image_node(emoji)?;
+        Ok((
+            image,
+            Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                ..default()
+            },
+            EmojiNode,
+        ))
+    }
+
+    /// Builds a UI emoji sized to match `font`, meant to sit between `Text` nodes
+    /// in a row so that labels can show emojis inline
+    pub fn ui_span(
+        &self,
+        emoji: impl Into<EmojiRef>,
+        font: &TextFont,
+    ) -> Result<impl Bundle, AtlasError> {
+        let image = self.image_node(emoji)?;
+        Ok((
+            image,
+            Node {
+                width: Val::Px(font.font_size),
+                height: Val::Px(font.font_size),
+                margin: UiRect::horizontal(Val::Px(font.font_size * 0.15)),
+                ..default()
+            },
+            EmojiNode,
+        ))
+    }
+
+    /// Spawns a standalone square UI emoji of `size` logical pixels
+    pub fn spawn_ui(
+        &self,
+        commands: &mut Commands,
+        emoji: impl Into<EmojiRef>,
+        size: f32,
+    ) -> Result<Entity, AtlasError> {
+        let bundle = self.ui_node(emoji, size)?;
+        Ok(commands.spawn(bundle).id())
+    }
+
+    /// Gets the alpha shape of an emoji, for pixel-accurate hit testing and colliders
+    pub fn shape(&self, emoji: impl Into<EmojiRef>) -> Result<&EmojiShape, AtlasError> {
+        let (atlas, index) = self.resolve(emoji)?;
+        atlas
+            .shapes
+            .get(index)
+            .and_then(Option::as_ref)
+            .ok_or(AtlasError::NoShape(index))
+    }
+
+    /// Picks `count` distinct random emoji indices from the default atlas
+    ///
+    /// Fails if the atlas is not ready or holds fewer than `count` emojis.
+    pub fn pick_random(&self, count: usize) -> Result<Vec<usize>, AtlasError> {
+        self.ready_atlas(AtlasId::DEFAULT)?.pick_random(count)
+    }
+
+    /// Picks `count` distinct random emojis from any atlas
+    pub fn pick_random_in(
+        &self,
+        atlas: AtlasId,
+        count: usize,
+    ) -> Result<Vec<EmojiRef>, AtlasError> {
+        Ok(self
+            .ready_atlas(atlas)?
+            .pick_random(count)?
+            .into_iter()
+            .map(|index| EmojiRef::new(atlas, index))
+            .collect())
+    }
+
+    /// Picks a single random emoji index from the default atlas
+    pub fn pick_one(&self) -> Result<usize, AtlasError> {
+        fastrand::choice(&self.ready_atlas(AtlasId::DEFAULT)?.valid_indices)
+            .copied()
+            .ok_or(AtlasError::NoValidCells)
+    }
+}
+
+#[cfg(test)]
+pub(super) mod test_utils {
+    use bevy::render::render_asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension};
+
+    use super::*;
+
+    /// A cell of `size` pixels, `color` where `opaque(x, y)` and transparent elsewhere
+    pub fn cell(size: UVec2, color: [u8; 3], opaque: impl Fn(u32, u32) -> bool) -> Vec<[u8; 4]> {
+        let [r, g, b] = color;
+        (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| (x, y)))
+            .map(|(x, y)| if opaque(x, y) { [r, g, b, 255] } else { [0; 4] })
+            .collect()
+    }
+
+    /// Builds an atlas of `cells` laid out in a single row, its texture loaded
+    pub fn atlas(cells: &[Vec<[u8; 4]>], cell_size: UVec2) -> (EmojiAtlas, Assets<Image>) {
+        let width = cell_size.x as usize;
+        let mut data = Vec::new();
+        for y in 0..cell_size.y as usize {
+            for cell in cells {
+                let row = cell
+                    .get(y * width..(y + 1) * width)
+                    .expect("Cells are cell_size large");
+                data.extend(row.iter().flatten());
+            }
+        }
+
+        let grid = UVec2::new(cells.len() as u32, 1);
+        let size = cell_size * grid;
+        let mut images = Assets::<Image>::default();
+        let texture = images.add(Image::new(
+            Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+
+        (
+            EmojiAtlas::new(texture, Handle::default(), cell_size, grid),
+            images,
+        )
+    }
+
+    /// Builds an atlas of `cells` laid out in a single row, loaded and analyzed
+    pub fn analyzed_atlas(
+        cells: &[Vec<[u8; 4]>],
+        cell_size: UVec2,
+    ) -> (EmojiAtlas, AtlasValidation) {
+        let (mut atlas, images) = atlas(cells, cell_size);
+        let validation = analyze(&mut atlas, &images);
+        (atlas, validation)
+    }
+
+    /// Analyzes and validates `atlas` as the loading frames would
+    pub fn analyze(atlas: &mut EmojiAtlas, images: &Assets<Image>) -> AtlasValidation {
+        let mut validation = AtlasValidation::default();
+        for _ in 0..=atlas.cell_count() / ANALYZED_CELLS_PER_FRAME {
+            atlas.analyze(&mut validation, images);
+        }
+        atlas.validate(&mut validation, images);
+        assert!(validation.is_loaded(), "The test atlas loads");
+        validation
+    }
+
+    /// A world holding `atlas` as the default atlas, to run systems using [`Emojis`]
+    pub fn emoji_world(atlas: EmojiAtlas, validation: AtlasValidation) -> World {
+        let mut world = World::new();
+        world.insert_resource(atlas);
+        world.insert_resource(validation);
+        world.init_resource::<EmojiAtlases>();
+        world
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{atlas, cell};
+    use super::*;
+
+    #[test]
+    fn analyzes_a_few_cells_per_frame() {
+        let size = UVec2::ONE;
+        let mut cells = vec![cell(size, [255; 3], |_, _| true); ANALYZED_CELLS_PER_FRAME + 1];
+        if let Some(empty) = cells.get_mut(1) {
+            *empty = cell(size, [255; 3], |_, _| false);
+        }
+        let (mut atlas, images) = atlas(&cells, size);
+        let mut validation = AtlasValidation::default();
+
+        atlas.analyze(&mut validation, &images);
+        atlas.validate(&mut validation, &images);
+        assert!(!validation.is_loaded());
+        assert_eq!(atlas.shapes.len(), ANALYZED_CELLS_PER_FRAME);
+
+        atlas.analyze(&mut validation, &images);
+        atlas.validate(&mut validation, &images);
+        assert!(validation.is_loaded());
+        assert_eq!(validation.total_emojis, ANALYZED_CELLS_PER_FRAME);
+        assert!(atlas.is_valid_index(0));
+        assert!(!atlas.is_valid_index(1));
+        assert!(atlas.is_valid_index(ANALYZED_CELLS_PER_FRAME));
+    }
+
+    #[test]
+    fn fails_on_a_wrong_sized_texture() {
+        let size = UVec2::ONE;
+        let (atlas, images) = atlas(&[cell(size, [255; 3], |_, _| true)], size);
+        let atlas = EmojiAtlas::new(atlas.texture, atlas.layout, size, UVec2::new(2, 1));
+        let mut validation = AtlasValidation::default();
+
+        atlas.validate(&mut validation, &images);
+        assert!(validation.is_failed());
+        assert!(!validation.is_loaded());
+    }
+}
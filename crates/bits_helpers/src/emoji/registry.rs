@@ -0,0 +1,329 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use super::{AtlasValidation, EmojiAtlas};
+
+/// Name of an emoji atlas, [`AtlasId::DEFAULT`] being the shared emoji atlas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AtlasId(pub &'static str);
+
+impl AtlasId {
+    pub const DEFAULT: Self = Self("emojis");
+}
+
+impl Default for AtlasId {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A cell of a specific atlas
+///
+/// Plain indices convert into references to the default atlas, so every API
+/// taking `impl Into<EmojiRef>` also accepts a `usize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmojiRef {
+    pub atlas: AtlasId,
+    pub index: usize,
+}
+
+impl EmojiRef {
+    pub const fn new(atlas: AtlasId, index: usize) -> Self {
+        Self { atlas, index }
+    }
+}
+
+impl From<usize> for EmojiRef {
+    fn from(index: usize) -> Self {
+        Self::new(AtlasId::DEFAULT, index)
+    }
+}
+
+/// One resolution of an atlas texture
+#[derive(Debug, Clone)]
+pub struct AtlasSource {
+    pub path: String,
+    /// Size in pixels of a single cell in this texture
+    pub cell_size: UVec2,
+}
+
+/// A sprite sheet of cells laid out on a regular grid, at one or more resolutions
+#[derive(Debug, Clone)]
+pub struct AtlasDescriptor {
+    /// Number of columns and rows, shared by every source
+    pub grid: UVec2,
+    /// Size in logical pixels cells are usually displayed at,
+    /// multiplied by the window scale factor to pick a source
+    pub display_size: f32,
+    pub sources: Vec<AtlasSource>,
+    /// Loads the atlas at startup instead of on first use
+    pub preload: bool,
+}
+
+impl AtlasDescriptor {
+    pub fn new(path: impl Into<String>, cell_size: UVec2, grid: UVec2) -> Self {
+        Self {
+            grid,
+            display_size: cell_size.max_element() as f32,
+            sources: vec![AtlasSource {
+                path: path.into(),
+                cell_size,
+            }],
+            preload: false,
+        }
+    }
+
+    /// Adds the same atlas at another resolution, e.g. 32 px cells for low density screens
+    #[must_use]
+    pub fn with_source(mut self, path: impl Into<String>, cell_size: UVec2) -> Self {
+        self.sources.push(AtlasSource {
+            path: path.into(),
+            cell_size,
+        });
+        self
+    }
+
+    #[must_use]
+    pub const fn with_display_size(mut self, display_size: f32) -> Self {
+        self.display_size = display_size;
+        self
+    }
+
+    #[must_use]
+    pub const fn preloaded(mut self) -> Self {
+        self.preload = true;
+        self
+    }
+
+    /// Picks the smallest source that stays sharp at `scale_factor`, or the largest one
+    pub fn source_for(&self, scale_factor: f32) -> Option<&AtlasSource> {
+        let wanted = self.display_size * scale_factor;
+        let size = |source: &&AtlasSource| source.cell_size.max_element();
+
+        self.sources
+            .iter()
+            .filter(|source| size(source) as f32 >= wanted)
+            .min_by_key(size)
+            .or_else(|| self.sources.iter().max_by_key(size))
+    }
+
+    /// Loads the best source for `scale_factor` and builds its grid layout
+    pub(super) fn load(
+        &self,
+        scale_factor: f32,
+        asset_server: &AssetServer,
+        layouts: &mut Assets<TextureAtlasLayout>,
+    ) -> Option<EmojiAtlas> {
+        let source = self.source_for(scale_factor)?;
+        let layout =
+            TextureAtlasLayout::from_grid(source.cell_size, self.grid.x, self.grid.y, None, None);
+
+        Some(EmojiAtlas::new(
+            asset_server.load(source.path.clone()),
+            layouts.add(layout),
+            source.cell_size,
+            self.grid,
+        ))
+    }
+}
+
+pub(super) struct RegisteredAtlas {
+    descriptor: AtlasDescriptor,
+    /// Set on first use, the atlas is then loaded by `load_requested_atlases`
+    requested: AtomicBool,
+    pub(super) atlas: Option<EmojiAtlas>,
+    pub(super) validation: AtlasValidation,
+}
+
+/// Registry of the atlases a bit can use besides the default emoji atlas
+///
+/// Atlases are loaded lazily: the first lookup through [`Emojis`](super::Emojis)
+/// requests the texture, and lookups fail with `NotReady` until it is analyzed.
+#[derive(Resource, Default)]
+pub struct EmojiAtlases {
+    atlases: HashMap<AtlasId, RegisteredAtlas>,
+}
+
+impl EmojiAtlases {
+    /// Registers an atlas, replacing any atlas previously registered under `id`
+    pub fn register(&mut self, id: AtlasId, descriptor: AtlasDescriptor) {
+        if id == AtlasId::DEFAULT {
+            warn!("Cannot replace the default emoji atlas");
+            return;
+        }
+
+        let previous = self.atlases.insert(
+            id,
+            RegisteredAtlas {
+                descriptor,
+                requested: AtomicBool::new(false),
+                atlas: None,
+                validation: AtlasValidation::default(),
+            },
+        );
+        if previous.is_some() {
+            warn!("Emoji atlas {id:?} registered twice, keeping the latest");
+        }
+    }
+
+    pub fn is_registered(&self, id: AtlasId) -> bool {
+        id == AtlasId::DEFAULT || self.atlases.contains_key(&id)
+    }
+
+    /// Starts loading an atlas ahead of its first use
+    pub fn request(&self, id: AtlasId) {
+        if let Some(registered) = self.atlases.get(&id) {
+            registered.requested.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub(super) fn get(&self, id: AtlasId) -> Option<&RegisteredAtlas> {
+        self.atlases.get(&id)
+    }
+
+    pub(super) fn loaded(&self) -> impl Iterator<Item = (AtlasId, &EmojiAtlas)> {
+        self.atlases
+            .iter()
+            .filter_map(|(&id, registered)| Some((id, registered.atlas.as_ref()?)))
+    }
+}
+
+/// Registers emoji atlases while building the app
+pub trait EmojiAtlasAppExt {
+    fn register_emoji_atlas(&mut self, id: AtlasId, descriptor: AtlasDescriptor) -> &mut Self;
+}
+
+impl EmojiAtlasAppExt for App {
+    fn register_emoji_atlas(&mut self, id: AtlasId, descriptor: AtlasDescriptor) -> &mut Self {
+        self.init_resource::<EmojiAtlases>();
+        self.world_mut()
+            .resource_mut::<EmojiAtlases>()
+            .register(id, descriptor);
+        self
+    }
+}
+
+pub(super) fn scale_factor(windows: &Query<&Window, With<PrimaryWindow>>) -> f32 {
+    windows.get_single().map_or(1.0, Window::scale_factor)
+}
+
+/// Run condition for [`load_requested_atlases`], so the registry is only taken mutably
+/// when an atlas waits to be loaded
+pub(super) fn has_requested_atlases(atlases: Res<EmojiAtlases>) -> bool {
+    atlases.atlases.values().any(|registered| {
+        registered.atlas.is_none()
+            && (registered.descriptor.preload || registered.requested.load(Ordering::Relaxed))
+    })
+}
+
+/// Run condition for the analysis and validation of registered atlases, true while a
+/// loaded atlas is not ready nor failed
+pub(super) fn has_pending_atlases(atlases: Res<EmojiAtlases>) -> bool {
+    atlases
+        .atlases
+        .values()
+        .any(|registered| registered.atlas.is_some() && registered.validation.is_pending())
+}
+
+pub(super) fn load_requested_atlases(
+    mut atlases: ResMut<EmojiAtlases>,
+    asset_server: Res<AssetServer>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    for (id, registered) in &mut atlases.atlases {
+        if registered.atlas.is_some()
+            || !(registered.descriptor.preload || *registered.requested.get_mut())
+        {
+            continue;
+        }
+
+        registered.atlas =
+            registered
+                .descriptor
+                .load(scale_factor(&windows), &asset_server, &mut layouts);
+        if registered.atlas.is_none() {
+            error!("Emoji atlas {id:?} has no texture source");
+            registered.descriptor.preload = false;
+            *registered.requested.get_mut() = false;
+        }
+    }
+}
+
+pub(super) fn analyze_registered_atlases(
+    mut atlases: ResMut<EmojiAtlases>,
+    images: Res<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+) {
+    for registered in atlases.atlases.values_mut() {
+        if let Some(atlas) = &mut registered.atlas {
+            atlas.check_texture(&mut registered.validation, &asset_server);
+            atlas.analyze(&mut registered.validation, &images);
+        }
+    }
+}
+
+pub(super) fn validate_registered_atlases(
+    mut atlases: ResMut<EmojiAtlases>,
+    images: Res<Assets<Image>>,
+) {
+    for registered in atlases.atlases.values_mut() {
+        if let Some(atlas) = &registered.atlas {
+            atlas.validate(&mut registered.validation, &images);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn only_has_work_once_requested() {
+        let id = AtlasId("test");
+        let mut atlases = EmojiAtlases::default();
+        atlases.register(id, AtlasDescriptor::new("test.png", UVec2::ONE, UVec2::ONE));
+        let mut world = World::new();
+        world.insert_resource(atlases);
+        let check = |world: &mut World| {
+            let requested = world
+                .run_system_once(has_requested_atlases)
+                .expect("The condition runs");
+            let pending = world
+                .run_system_once(has_pending_atlases)
+                .expect("The condition runs");
+            (requested, pending)
+        };
+
+        assert_eq!(check(&mut world), (false, false));
+        world.resource::<EmojiAtlases>().request(id);
+        assert_eq!(check(&mut world), (true, false));
+
+        let mut atlases = world.resource_mut::<EmojiAtlases>();
+        let registered = atlases
+            .atlases
+            .get_mut(&id)
+            .expect("The atlas is registered");
+        registered.atlas = Some(EmojiAtlas::new(
+            Handle::default(),
+            Handle::default(),
+            UVec2::ONE,
+            UVec2::ONE,
+        ));
+        assert_eq!(check(&mut world), (false, true));
+
+        world
+            .resource_mut::<EmojiAtlases>()
+            .atlases
+            .get_mut(&id)
+            .expect("The atlas is registered")
+            .validation
+            .is_failed = true;
+        assert_eq!(check(&mut world), (false, false));
+    }
+}
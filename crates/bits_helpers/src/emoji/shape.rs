@@ -0,0 +1,409 @@
+use bevy::math::bounding::BoundingCircle;
+use bevy::prelude::*;
+
+use super::Emojis;
+
+/// Alpha above which a pixel counts as part of the emoji
+pub const ALPHA_THRESHOLD: u8 = 128;
+
+/// Shape of an emoji derived from the alpha channel of its atlas cell
+///
+/// Everything is expressed in normalized sprite space: the cell spans
+/// `-0.5..=0.5` on both axes, with y pointing up like world space.
+/// Multiply by the displayed sprite size to get local coordinates.
+#[derive(Debug, Clone)]
+pub struct EmojiShape {
+    /// Size in pixels of the atlas cell the shape was derived from
+    cell_size: UVec2,
+    /// One bit per pixel, row-major from the top left of the cell
+    mask: Vec<u64>,
+    bounds: Rect,
+    hull: Vec<Vec2>,
+    circle: BoundingCircle,
+}
+
+impl EmojiShape {
+    /// Builds the shape from the RGBA pixels of a cell, or `None` if no pixel is opaque enough
+    pub(super) fn from_pixels(pixels: &[[u8; 4]], cell_size: UVec2) -> Option<Self> {
+        let width = (cell_size.x as usize).max(1);
+        let mut mask = vec![0u64; pixels.len().div_ceil(64)];
+        let mut min = UVec2::MAX;
+        let mut max = UVec2::ZERO;
+        // Pixel corners of the leftmost and rightmost opaque pixels of each row
+        let mut outline = Vec::new();
+
+        for (y, row) in pixels.chunks(width).enumerate() {
+            let mut row_span: Option<(usize, usize)> = None;
+            for (x, &[_, _, _, alpha]) in row.iter().enumerate() {
+                if alpha < ALPHA_THRESHOLD {
+                    continue;
+                }
+                let bit = y * width + x;
+                if let Some(word) = mask.get_mut(bit / 64) {
+                    *word |= 1 << (bit % 64);
+                }
+                row_span = Some(row_span.map_or((x, x), |(left, _)| (left, x)));
+            }
+
+            if let Some((left, right)) = row_span {
+                let (left, right, y) = (left as u32, right as u32 + 1, y as u32);
+                min = min.min(UVec2::new(left, y));
+                max = max.max(UVec2::new(right, y + 1));
+                outline.extend([
+                    UVec2::new(left, y),
+                    UVec2::new(left, y + 1),
+                    UVec2::new(right, y),
+                    UVec2::new(right, y + 1),
+                ]);
+            }
+        }
+
+        if outline.is_empty() {
+            return None;
+        }
+
+        let to_normalized = |pixel: UVec2| {
+            let uv = pixel.as_vec2() / cell_size.as_vec2();
+            Vec2::new(uv.x - 0.5, 0.5 - uv.y)
+        };
+        let normalized: Vec<Vec2> = outline.into_iter().map(to_normalized).collect();
+        let hull = convex_hull(normalized);
+        let circle = enclosing_circle(&hull);
+        let bounds = Rect::from_corners(to_normalized(min), to_normalized(max));
+
+        Some(Self {
+            cell_size,
+            mask,
+            bounds,
+            hull,
+            circle,
+        })
+    }
+
+    /// Size in pixels of the atlas cell, the emoji's native display size
+    pub const fn cell_size(&self) -> UVec2 {
+        self.cell_size
+    }
+
+    /// Tight bounding box around the opaque pixels
+    pub const fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Convex hull around the opaque pixels, counter-clockwise
+    pub fn hull(&self) -> &[Vec2] {
+        &self.hull
+    }
+
+    /// Smallest circle enclosing the opaque pixels
+    pub const fn circle(&self) -> BoundingCircle {
+        self.circle
+    }
+
+    /// Returns whether the pixel under a normalized point is opaque
+    pub fn contains_normalized(&self, point: Vec2) -> bool {
+        let uv = Vec2::new(point.x + 0.5, 0.5 - point.y);
+        if uv.cmplt(Vec2::ZERO).any() || uv.cmpge(Vec2::ONE).any() {
+            return false;
+        }
+
+        let pixel = (uv * self.cell_size.as_vec2()).as_uvec2();
+        let bit = (pixel.y * self.cell_size.x + pixel.x) as usize;
+        self.mask
+            .get(bit / 64)
+            .is_some_and(|word| word & (1 << (bit % 64)) != 0)
+    }
+
+    /// Convex hull scaled to an emoji displayed at `size`
+    pub fn hull_points(&self, size: Vec2) -> Vec<Vec2> {
+        self.hull.iter().map(|&point| point * size).collect()
+    }
+
+    /// Convex hull collider for an emoji displayed at `size`
+    #[cfg(feature = "avian2d")]
+    pub fn hull_collider(&self, size: Vec2) -> Option<avian2d::prelude::Collider> {
+        avian2d::prelude::Collider::convex_hull(self.hull_points(size))
+    }
+
+    /// Fitted circle collider for an emoji displayed at `size`
+    ///
+    /// The circle is offset when the emoji is not centered in its cell.
+    #[cfg(feature = "avian2d")]
+    pub fn circle_collider(&self, size: Vec2) -> avian2d::prelude::Collider {
+        use avian2d::prelude::{Collider, Position, Rotation};
+
+        let scale = size.max_element();
+        let circle = Collider::circle(self.circle.radius() * scale);
+        let center = self.circle.center * size;
+        if center == Vec2::ZERO {
+            circle
+        } else {
+            Collider::compound(vec![(Position::new(center), Rotation::default(), circle)])
+        }
+    }
+}
+
+/// Returns whether `point`, in world space, hits an opaque pixel of an emoji sprite
+///
+/// Takes the sprite size, anchor, flips and the full transform into account,
+/// so rotated and scaled emojis are tested accurately.
+pub fn emoji_contains_point(
+    emojis: &Emojis,
+    sprite: &Sprite,
+    transform: &GlobalTransform,
+    point: Vec2,
+) -> bool {
+    let Some(emoji) = emojis.sprite_ref(sprite) else {
+        return false;
+    };
+    let Ok(shape) = emojis.shape(emoji) else {
+        return false;
+    };
+
+    let size = sprite
+        .custom_size
+        .unwrap_or_else(|| shape.cell_size.as_vec2());
+    if size.cmple(Vec2::ZERO).any() {
+        return false;
+    }
+
+    let local = transform
+        .affine()
+        .inverse()
+        .transform_point3(point.extend(0.0))
+        .truncate();
+    let mut normalized = local / size + sprite.anchor.as_vec();
+    if sprite.flip_x {
+        normalized.x = -normalized.x;
+    }
+    if sprite.flip_y {
+        normalized.y = -normalized.y;
+    }
+
+    shape.contains_normalized(normalized)
+}
+
+/// Andrew's monotone chain, returns the hull counter-clockwise without collinear points
+fn convex_hull(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull = half_hull(points.iter().copied());
+    hull.extend(half_hull(points.iter().rev().copied()));
+    hull
+}
+
+/// Builds one side of the hull, dropping its last point which starts the other side
+fn half_hull(points: impl Iterator<Item = Vec2>) -> Vec<Vec2> {
+    let mut chain: Vec<Vec2> = Vec::new();
+    for point in points {
+        while let [.., a, b] = chain[..] {
+            if (b - a).perp_dot(point - a) > 0.0 {
+                break;
+            }
+            chain.pop();
+        }
+        chain.push(point);
+    }
+    chain.pop();
+    chain
+}
+
+/// Smallest circle enclosing all points, using the incremental Welzl construction
+fn enclosing_circle(points: &[Vec2]) -> BoundingCircle {
+    let contains = |circle: &BoundingCircle, point: Vec2| {
+        circle.center.distance(point) <= circle.radius() + 1e-5
+    };
+    let diameter = |a: Vec2, b: Vec2| BoundingCircle::new(a.midpoint(b), a.distance(b) * 0.5);
+
+    let Some(&first) = points.first() else {
+        return BoundingCircle::new(Vec2::ZERO, 0.0);
+    };
+    let mut circle = BoundingCircle::new(first, 0.0);
+
+    for (i, &a) in points.iter().enumerate() {
+        if contains(&circle, a) {
+            continue;
+        }
+        circle = BoundingCircle::new(a, 0.0);
+        for (j, &b) in points.iter().take(i).enumerate() {
+            if contains(&circle, b) {
+                continue;
+            }
+            circle = diameter(a, b);
+            for &c in points.iter().take(j) {
+                if !contains(&circle, c) {
+                    circle = circumcircle(a, b, c).unwrap_or_else(|| {
+                        // Collinear points, the farthest pair spans the circle
+                        [diameter(a, b), diameter(a, c), diameter(b, c)]
+                            .into_iter()
+                            .max_by(|x, y| x.radius().total_cmp(&y.radius()))
+                            .unwrap_or(circle)
+                    });
+                }
+            }
+        }
+    }
+
+    circle
+}
+
+fn circumcircle(a: Vec2, b: Vec2, c: Vec2) -> Option<BoundingCircle> {
+    let ab = b - a;
+    let ac = c - a;
+    let denominator = 2.0 * ab.perp_dot(ac);
+    if denominator.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let offset = (ab.perp() * ac.length_squared() - ac.perp() * ab.length_squared()) / denominator;
+    Some(BoundingCircle::new(a + offset, offset.length()))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::sprite::Anchor;
+
+    use super::super::test_utils::{analyzed_atlas, cell, emoji_world};
+    use super::*;
+
+    const SIZE: UVec2 = UVec2::new(4, 4);
+
+    /// Opaque in the top left quarter of the cell
+    const fn top_left(x: u32, y: u32) -> bool {
+        x < 2 && y < 2
+    }
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!(a.distance(b) < 1e-5, "{a} is not {b}");
+    }
+
+    #[test]
+    fn builds_the_hull_counter_clockwise() {
+        let square = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(-1.0, 1.0),
+        ];
+        // Inner, duplicate and collinear points are dropped
+        let mut points = square.to_vec();
+        points.extend([Vec2::ZERO, Vec2::new(1.0, 1.0), Vec2::new(0.0, 1.0)]);
+
+        let hull = convex_hull(points);
+        assert_eq!(
+            hull,
+            [
+                Vec2::new(-1.0, -1.0),
+                Vec2::new(1.0, -1.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(-1.0, 1.0),
+            ]
+        );
+        assert_eq!(convex_hull(vec![Vec2::ONE, Vec2::ONE]), [Vec2::ONE]);
+    }
+
+    #[test]
+    fn encloses_points_in_the_smallest_circle() {
+        let square = [
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+        ];
+        let circle = enclosing_circle(&square);
+        assert_near(circle.center, Vec2::ZERO);
+        assert!((circle.radius() - core::f32::consts::SQRT_2).abs() < 1e-5);
+
+        // The longest side of an obtuse triangle is a diameter
+        let circle = enclosing_circle(&[
+            Vec2::new(-2.0, 0.0),
+            Vec2::new(0.0, 0.5),
+            Vec2::new(2.0, 0.0),
+        ]);
+        assert_near(circle.center, Vec2::ZERO);
+        assert!((circle.radius() - 2.0).abs() < 1e-5);
+
+        // Collinear points span the circle with their farthest pair
+        let circle = enclosing_circle(&[Vec2::ZERO, Vec2::X, Vec2::X * 4.0]);
+        assert_near(circle.center, Vec2::X * 2.0);
+        assert!((circle.radius() - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn derives_the_shape_from_alpha() {
+        let mut pixels = cell(SIZE, [255; 3], top_left);
+        // Faint pixels are not part of the shape
+        if let Some(pixel) = pixels.get_mut(15) {
+            *pixel = [255, 255, 255, ALPHA_THRESHOLD - 1];
+        }
+        let shape = EmojiShape::from_pixels(&pixels, SIZE).expect("The cell has opaque pixels");
+
+        assert_near(shape.bounds().min, Vec2::new(-0.5, 0.0));
+        assert_near(shape.bounds().max, Vec2::new(0.0, 0.5));
+        assert_eq!(shape.hull().len(), 4);
+        assert_near(shape.circle().center, Vec2::new(-0.25, 0.25));
+        assert!(shape.contains_normalized(Vec2::new(-0.25, 0.25)));
+        assert!(!shape.contains_normalized(Vec2::new(0.25, 0.25)));
+        assert!(!shape.contains_normalized(Vec2::new(0.4, -0.4)));
+        assert!(!shape.contains_normalized(Vec2::new(-0.75, 0.25)));
+
+        assert!(EmojiShape::from_pixels(&cell(SIZE, [255; 3], |_, _| false), SIZE).is_none());
+    }
+
+    #[test]
+    fn hits_emojis_with_anchor_and_flips() {
+        let (atlas, validation) = analyzed_atlas(&[cell(SIZE, [255; 3], top_left)], SIZE);
+        let mut world = emoji_world(atlas, validation);
+
+        world
+            .run_system_once(|emojis: Emojis| {
+                let sprite = emojis
+                    .sprite_sized(0, Vec2::splat(40.0))
+                    .expect("The emoji is in the atlas");
+                let at =
+                    |translation: Vec2| GlobalTransform::from_translation(translation.extend(0.0));
+                let hits = |sprite: &Sprite, transform: &GlobalTransform, point: Vec2| {
+                    emoji_contains_point(&emojis, sprite, transform, point)
+                };
+
+                // Centered, the opaque quarter is up and to the left
+                assert!(hits(&sprite, &at(Vec2::ZERO), Vec2::new(-10.0, 10.0)));
+                assert!(!hits(&sprite, &at(Vec2::ZERO), Vec2::new(10.0, 10.0)));
+                assert!(hits(&sprite, &at(Vec2::X * 100.0), Vec2::new(90.0, 10.0)));
+
+                let flipped = Sprite {
+                    flip_x: true,
+                    ..sprite.clone()
+                };
+                assert!(hits(&flipped, &at(Vec2::ZERO), Vec2::new(10.0, 10.0)));
+                assert!(!hits(&flipped, &at(Vec2::ZERO), Vec2::new(-10.0, 10.0)));
+                let flipped = Sprite {
+                    flip_y: true,
+                    ..sprite.clone()
+                };
+                assert!(hits(&flipped, &at(Vec2::ZERO), Vec2::new(-10.0, -10.0)));
+
+                // Anchored at its top left corner, the sprite hangs below and to the right
+                let anchored = Sprite {
+                    anchor: Anchor::TopLeft,
+                    ..sprite.clone()
+                };
+                assert!(hits(&anchored, &at(Vec2::ZERO), Vec2::new(10.0, -10.0)));
+                assert!(!hits(&anchored, &at(Vec2::ZERO), Vec2::new(-10.0, 10.0)));
+                assert!(!hits(&anchored, &at(Vec2::ZERO), Vec2::new(30.0, -30.0)));
+
+                // Rotated half a turn, the opaque quarter is down and to the right
+                let rotated = GlobalTransform::from(Transform::from_rotation(
+                    Quat::from_rotation_z(core::f32::consts::PI),
+                ));
+                assert!(hits(&sprite, &rotated, Vec2::new(10.0, -10.0)));
+                assert!(!hits(&sprite, &rotated, Vec2::new(-10.0, 10.0)));
+            })
+            .expect("The system runs");
+    }
+}
@@ -0,0 +1,338 @@
+use bevy::math::UVec2;
+
+use super::{AtlasError, AtlasId, EmojiAtlas, EmojiRef, Emojis};
+
+/// Color levels kept per channel, giving `HISTOGRAM_LEVELS³` histogram bins
+const HISTOGRAM_LEVELS: usize = 4;
+const HISTOGRAM_BINS: usize = HISTOGRAM_LEVELS * HISTOGRAM_LEVELS * HISTOGRAM_LEVELS;
+/// Cells per side of the downsampled alpha grid describing the silhouette
+const SHAPE_GRID: usize = 8;
+/// Share of the color histogram in the distance, the rest comes from the silhouette
+const COLOR_WEIGHT: f32 = 0.6;
+
+/// Visual descriptors of an emoji, used to compare how alike two emojis look
+#[derive(Debug, Clone)]
+pub struct EmojiFeatures {
+    /// Alpha-weighted color histogram, sums to 1 for any visible emoji
+    histogram: [f32; HISTOGRAM_BINS],
+    /// Average opacity of each cell of a coarse grid over the emoji
+    coverage: [f32; SHAPE_GRID * SHAPE_GRID],
+}
+
+impl EmojiFeatures {
+    pub(super) fn from_pixels(pixels: &[[u8; 4]], cell_size: UVec2) -> Self {
+        let width = (cell_size.x as usize).max(1);
+        let block = (width / SHAPE_GRID).max(1);
+        let block_height = (cell_size.y as usize / SHAPE_GRID).max(1);
+        let shift = 8 - HISTOGRAM_LEVELS.trailing_zeros();
+
+        let mut histogram = [0.0; HISTOGRAM_BINS];
+        let mut coverage = [0.0; SHAPE_GRID * SHAPE_GRID];
+        let mut counts = [0_u32; SHAPE_GRID * SHAPE_GRID];
+
+        for (i, &[r, g, b, a]) in pixels.iter().enumerate() {
+            let weight = a as f32 / 255.0;
+
+            let bin = ((r >> shift) as usize * HISTOGRAM_LEVELS + (g >> shift) as usize)
+                * HISTOGRAM_LEVELS
+                + (b >> shift) as usize;
+            if let Some(count) = histogram.get_mut(bin) {
+                *count += weight;
+            }
+
+            // Edge pixels left over when the cell size is not a multiple of the grid
+            // fall in the last row or column
+            let row = (i / width / block_height).min(SHAPE_GRID - 1);
+            let column = ((i % width) / block).min(SHAPE_GRID - 1);
+            let cell = row * SHAPE_GRID + column;
+            if let (Some(opacity), Some(count)) = (coverage.get_mut(cell), counts.get_mut(cell)) {
+                *opacity += weight;
+                *count += 1;
+            }
+        }
+
+        let total: f32 = histogram.iter().sum();
+        if total > 0.0 {
+            for count in &mut histogram {
+                *count /= total;
+            }
+        }
+        // The last row and column may hold more pixels than the others
+        for (opacity, &count) in coverage.iter_mut().zip(&counts) {
+            if count > 0 {
+                *opacity /= count as f32;
+            }
+        }
+
+        Self {
+            histogram,
+            coverage,
+        }
+    }
+
+    /// Visual distance in `0.0..=1.0`, 0 meaning identical colors and silhouette
+    pub fn distance(&self, other: &Self) -> f32 {
+        let color = 0.5
+            * self
+                .histogram
+                .iter()
+                .zip(&other.histogram)
+                .map(|(a, b)| (a - b).abs())
+                .sum::<f32>();
+        let shape = self
+            .coverage
+            .iter()
+            .zip(&other.coverage)
+            .map(|(a, b)| (a - b).abs())
+            .sum::<f32>()
+            / self.coverage.len() as f32;
+
+        COLOR_WEIGHT.mul_add(color, (1.0 - COLOR_WEIGHT) * shape)
+    }
+}
+
+impl EmojiAtlas {
+    fn pick_distinct(&self, count: usize, min_distance: f32) -> Result<Vec<usize>, AtlasError> {
+        let mut candidates = self.valid_indices.clone();
+        fastrand::shuffle(&mut candidates);
+
+        let mut picked: Vec<(usize, &EmojiFeatures)> = Vec::with_capacity(count);
+        for candidate in candidates {
+            if picked.len() == count {
+                break;
+            }
+            let Some(features) = self.features.get(candidate).and_then(Option::as_ref) else {
+                continue;
+            };
+            if picked
+                .iter()
+                .all(|(_, other)| features.distance(other) >= min_distance)
+            {
+                picked.push((candidate, features));
+            }
+        }
+
+        if picked.len() < count {
+            return Err(AtlasError::NotEnoughEmojis {
+                requested: count,
+                available: picked.len(),
+            });
+        }
+
+        Ok(picked.into_iter().map(|(index, _)| index).collect())
+    }
+}
+
+impl Emojis<'_> {
+    /// Gets the visual descriptors of an emoji
+    pub fn features(&self, emoji: impl Into<EmojiRef>) -> Result<&EmojiFeatures, AtlasError> {
+        let (atlas, index) = self.resolve(emoji)?;
+        atlas
+            .features
+            .get(index)
+            .and_then(Option::as_ref)
+            .ok_or(AtlasError::InvalidIndex(index))
+    }
+
+    /// Visual distance between two emojis in `0.0..=1.0`, 0 meaning they look alike
+    ///
+    /// The emojis may come from different atlases, even with different cell sizes.
+    pub fn distance(
+        &self,
+        a: impl Into<EmojiRef>,
+        b: impl Into<EmojiRef>,
+    ) -> Result<f32, AtlasError> {
+        Ok(self.features(a)?.distance(self.features(b)?))
+    }
+
+    /// Picks the `count` emojis of the same atlas that look the most like `emoji`, closest first
+    ///
+    /// Useful for decoys on hard stages. `emoji` itself is never picked.
+    pub fn pick_similar_to(
+        &self,
+        emoji: impl Into<EmojiRef>,
+        count: usize,
+    ) -> Result<Vec<EmojiRef>, AtlasError> {
+        let emoji = emoji.into();
+        let target = self.features(emoji)?;
+        let (atlas, _) = self.resolve(emoji)?;
+
+        let mut candidates: Vec<(f32, usize)> = atlas
+            .valid_indices
+            .iter()
+            .filter(|&&candidate| candidate != emoji.index)
+            .filter_map(|&candidate| {
+                let features = atlas.features.get(candidate)?.as_ref()?;
+                Some((target.distance(features), candidate))
+            })
+            .collect();
+
+        if count > candidates.len() {
+            return Err(AtlasError::NotEnoughEmojis {
+                requested: count,
+                available: candidates.len(),
+            });
+        }
+
+        candidates.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(candidates
+            .into_iter()
+            .take(count)
+            .map(|(_, candidate)| EmojiRef::new(emoji.atlas, candidate))
+            .collect())
+    }
+
+    /// Picks `count` random emojis of the default atlas that are all at least `min_distance` apart
+    ///
+    /// Useful for legends and sets the player must tell apart at a glance.
+    /// Fails if the atlas does not hold enough emojis that distinct from each other.
+    pub fn pick_distinct(&self, count: usize, min_distance: f32) -> Result<Vec<usize>, AtlasError> {
+        self.ready_atlas(AtlasId::DEFAULT)?
+            .pick_distinct(count, min_distance)
+    }
+
+    /// Same as [`Emojis::pick_distinct`], picking from any atlas
+    pub fn pick_distinct_in(
+        &self,
+        atlas: AtlasId,
+        count: usize,
+        min_distance: f32,
+    ) -> Result<Vec<EmojiRef>, AtlasError> {
+        Ok(self
+            .ready_atlas(atlas)?
+            .pick_distinct(count, min_distance)?
+            .into_iter()
+            .map(|index| EmojiRef::new(atlas, index))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+
+    use super::super::test_utils::{analyzed_atlas, cell, emoji_world};
+    use super::*;
+
+    const SIZE: UVec2 = UVec2::new(8, 8);
+    const RED: [u8; 3] = [255, 0, 0];
+    const BLUE: [u8; 3] = [0, 0, 255];
+
+    fn features(pixels: &[[u8; 4]]) -> EmojiFeatures {
+        EmojiFeatures::from_pixels(pixels, SIZE)
+    }
+
+    #[test]
+    fn describes_colors_and_silhouette() {
+        let left_half = features(&cell(SIZE, RED, |x, _| x < 4));
+
+        assert_eq!(
+            left_half.histogram.iter().filter(|&&bin| bin > 0.0).count(),
+            1
+        );
+        assert!((left_half.histogram.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        for (i, &opacity) in left_half.coverage.iter().enumerate() {
+            let expected = if i % SHAPE_GRID < 4 { 1.0 } else { 0.0 };
+            assert!((opacity - expected).abs() < 1e-6, "cell {i} is {opacity}");
+        }
+    }
+
+    #[test]
+    fn keeps_leftover_pixels_in_the_last_cells() {
+        // 10 is not a multiple of the grid, the last columns and rows are leftovers
+        let size = UVec2::new(10, 10);
+        let features = EmojiFeatures::from_pixels(&cell(size, RED, |x, _| x == 9), size);
+
+        let column = |column: usize| {
+            (0..SHAPE_GRID)
+                .map(|row| features.coverage.get(row * SHAPE_GRID + column).copied())
+                .collect::<Option<Vec<f32>>>()
+                .expect("The column is in the grid")
+        };
+        assert!(column(SHAPE_GRID - 1).iter().all(|&opacity| opacity > 0.0));
+        assert!(column(0).iter().all(|&opacity| opacity == 0.0));
+        assert!(features.coverage.iter().all(|&opacity| opacity <= 1.0));
+
+        // Leftover cells are averaged over their own pixels, so opacity stays in range
+        let opaque = EmojiFeatures::from_pixels(&cell(size, RED, |_, _| true), size);
+        assert!(
+            opaque
+                .coverage
+                .iter()
+                .all(|&opacity| (opacity - 1.0).abs() < 1e-6)
+        );
+        let empty = EmojiFeatures::from_pixels(&cell(size, RED, |_, _| false), size);
+        assert!(opaque.distance(&empty) <= 1.0);
+    }
+
+    #[test]
+    fn measures_distance() {
+        let red = features(&cell(SIZE, RED, |_, _| true));
+        let blue = features(&cell(SIZE, BLUE, |_, _| true));
+        let red_half = features(&cell(SIZE, RED, |x, _| x < 4));
+
+        assert!(red.distance(&red).abs() < 1e-6);
+        assert!((red.distance(&blue) - COLOR_WEIGHT).abs() < 1e-6);
+        assert!((red.distance(&blue) - blue.distance(&red)).abs() < 1e-6);
+        // Half the silhouette differs
+        let shape = (1.0 - COLOR_WEIGHT) * 0.5;
+        assert!((red.distance(&red_half) - shape).abs() < 1e-6);
+        assert!(red.distance(&red_half) < red.distance(&blue));
+    }
+
+    #[test]
+    fn picks_distinct_emojis() {
+        let (atlas, _) = analyzed_atlas(
+            &[
+                cell(SIZE, RED, |_, _| true),
+                cell(SIZE, RED, |_, _| true),
+                cell(SIZE, BLUE, |_, _| true),
+                cell(SIZE, BLUE, |_, _| false),
+            ],
+            SIZE,
+        );
+
+        for _ in 0..10 {
+            let mut picked = atlas
+                .pick_distinct(2, 0.5)
+                .expect("Red and blue are distinct");
+            picked.sort_unstable();
+            assert!(matches!(picked.as_slice(), [0 | 1, 2]), "picked {picked:?}");
+        }
+        assert!(matches!(
+            atlas.pick_distinct(3, 0.5),
+            Err(AtlasError::NotEnoughEmojis {
+                requested: 3,
+                available: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn picks_similar_emojis_closest_first() {
+        let (atlas, validation) = analyzed_atlas(
+            &[
+                cell(SIZE, RED, |_, _| true),
+                cell(SIZE, BLUE, |_, _| true),
+                cell(SIZE, RED, |x, _| x < 4),
+                cell(SIZE, BLUE, |_, _| false),
+            ],
+            SIZE,
+        );
+        emoji_world(atlas, validation)
+            .run_system_once(|emojis: Emojis| {
+                let similar = emojis.pick_similar_to(0, 2).expect("Two emojis look alike");
+                assert_eq!(similar, [EmojiRef::from(2), EmojiRef::from(1)]);
+                assert!(matches!(
+                    emojis.pick_similar_to(0, 3),
+                    Err(AtlasError::NotEnoughEmojis {
+                        requested: 3,
+                        available: 2
+                    })
+                ));
+                assert!(emojis.pick_similar_to(3, 1).is_err());
+            })
+            .expect("The system runs");
+    }
+}
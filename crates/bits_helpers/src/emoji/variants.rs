@@ -0,0 +1,484 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::utils::HashMap;
+
+use super::{AtlasError, EmojiRef, EmojiSprite, Emojis};
+
+/// Modification applied on the CPU to an atlas cell to produce a new image
+///
+/// Parameters are stored as integers so that variants can be used as cache keys,
+/// use the constructors to build them from floats and colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmojiVariant {
+    /// Every visible pixel painted black, keeping the outline
+    Silhouette,
+    /// Colors multiplied by an sRGB tint
+    Tint([u8; 3]),
+    /// Hues rotated by the given number of degrees
+    HueShift(i16),
+    /// Blocks of `n`×`n` pixels averaged together
+    Pixelate(u8),
+    /// Box blur with the given radius in pixels
+    Blur(u8),
+    /// Flipped horizontally
+    Mirrored,
+    /// Only the part of the emoji covered by the mask, `revealed` ranges over `0..=255`
+    Reveal { mask: RevealMask, revealed: u8 },
+}
+
+/// Order in which a partially revealed emoji is uncovered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RevealMask {
+    LeftToRight,
+    TopToBottom,
+    /// From the center outwards
+    Radial,
+    /// Square tiles uncovered in a random order
+    Tiles {
+        size: u8,
+        seed: u64,
+    },
+}
+
+impl EmojiVariant {
+    pub fn tint(color: Color) -> Self {
+        let [red, green, blue, _] = color.to_srgba().to_u8_array();
+        Self::Tint([red, green, blue])
+    }
+
+    pub fn hue_shift(degrees: f32) -> Self {
+        Self::HueShift(degrees.rem_euclid(360.0).round() as i16)
+    }
+
+    /// Reveals `fraction` (`0.0..=1.0`) of the emoji following `mask`
+    ///
+    /// The fraction is quantized to 256 steps, which bounds the number of cached images
+    /// when animating a reveal.
+    pub fn reveal(mask: RevealMask, fraction: f32) -> Self {
+        Self::Reveal {
+            mask,
+            revealed: (fraction.clamp(0.0, 1.0) * 255.0).round() as u8,
+        }
+    }
+
+    fn apply(self, pixels: &[[u8; 4]], size: UVec2) -> Vec<[u8; 4]> {
+        match self {
+            Self::Silhouette => pixels.iter().map(|&[_, _, _, a]| [0, 0, 0, a]).collect(),
+            Self::Tint([tint_r, tint_g, tint_b]) => {
+                let multiply = |channel: u8, tint: u8| (channel as u16 * tint as u16 / 255) as u8;
+                pixels
+                    .iter()
+                    .map(|&[r, g, b, a]| {
+                        [
+                            multiply(r, tint_r),
+                            multiply(g, tint_g),
+                            multiply(b, tint_b),
+                            a,
+                        ]
+                    })
+                    .collect()
+            }
+            Self::HueShift(degrees) => pixels
+                .iter()
+                .map(|&[r, g, b, a]| {
+                    let hsla = Hsla::from(Srgba::rgba_u8(r, g, b, a));
+                    let shifted = hsla.with_hue((hsla.hue + degrees as f32).rem_euclid(360.0));
+                    Srgba::from(shifted).to_u8_array()
+                })
+                .collect(),
+            Self::Pixelate(block) => pixelate(pixels, size, block.max(1) as usize),
+            Self::Blur(radius) => blur(pixels, size, radius as usize),
+            Self::Mirrored => pixels
+                .chunks((size.x as usize).max(1))
+                .flat_map(|row| row.iter().rev().copied())
+                .collect(),
+            Self::Reveal { mask, revealed } => reveal(pixels, size, mask, revealed),
+        }
+    }
+}
+
+/// Strong handles to every generated variant, so each one is only computed once
+#[derive(Resource, Default)]
+pub struct EmojiVariantCache {
+    images: HashMap<(EmojiRef, EmojiVariant), Handle<Image>>,
+}
+
+impl EmojiVariantCache {
+    /// Drops all generated images, e.g. when leaving a bit stage that used many reveal steps
+    pub fn clear(&mut self) {
+        self.images.clear();
+    }
+}
+
+/// System parameter generating modified emoji images from the atlas
+#[derive(SystemParam)]
+pub struct EmojiVariants<'w> {
+    emojis: Emojis<'w>,
+    images: ResMut<'w, Assets<Image>>,
+    cache: ResMut<'w, EmojiVariantCache>,
+}
+
+impl EmojiVariants<'_> {
+    /// Gets the image of an emoji with `variant` applied, generating it on first use
+    pub fn image(
+        &mut self,
+        emoji: impl Into<EmojiRef>,
+        variant: EmojiVariant,
+    ) -> Result<Handle<Image>, AtlasError> {
+        let emoji = self.emojis.validate(emoji)?;
+        if let Some(handle) = self.cache.images.get(&(emoji, variant)) {
+            return Ok(handle.clone());
+        }
+
+        let (atlas, index) = self.emojis.resolve(emoji)?;
+        let atlas_image = self
+            .images
+            .get(&atlas.texture)
+            .ok_or(AtlasError::NotReady)?;
+        let size = atlas.cell_size;
+        let pixels = variant.apply(&atlas.read_cell(atlas_image, index)?, size);
+
+        let image = Image::new(
+            Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            pixels.into_flattened(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let handle = self.images.add(image);
+        self.cache.images.insert((emoji, variant), handle.clone());
+
+        Ok(handle)
+    }
+
+    /// Builds a sprite displaying the variant at the given size in world units
+    pub fn sprite_sized(
+        &mut self,
+        emoji: impl Into<EmojiRef>,
+        variant: EmojiVariant,
+        size: Vec2,
+    ) -> Result<Sprite, AtlasError> {
+        Ok(Sprite {
+            image: self.image(emoji, variant)?,
+            custom_size: Some(size),
+            ..default()
+        })
+    }
+
+    /// Spawns a sprite entity displaying the variant at the given size in world units
+    pub fn spawn_sized(
+        &mut self,
+        commands: &mut Commands,
+        emoji: impl Into<EmojiRef>,
+        variant: EmojiVariant,
+        size: Vec2,
+        transform: Transform,
+    ) -> Result<Entity, AtlasError> {
+        let sprite = self.sprite_sized(emoji, variant, size)?;
+        Ok(commands
+            .spawn((sprite, transform, Visibility::Visible, EmojiSprite))
+            .id())
+    }
+}
+
+/// Scales colors by their opacity, kept unrounded so averages are exact
+const fn premultiply([r, g, b, a]: [u8; 4]) -> [u64; 4] {
+    let alpha = a as u64;
+    [r as u64 * alpha, g as u64 * alpha, b as u64 * alpha, alpha]
+}
+
+/// Averages premultiplied colors so transparent pixels do not darken the edges
+fn average(sum: [u64; 4], count: u64) -> [u8; 4] {
+    let [r, g, b, a] = sum;
+    if a == 0 {
+        return [0; 4];
+    }
+    // Rounded divisions, a lone pixel is kept as is
+    let unpremultiply = |channel: u64| ((channel + a / 2) / a).min(255) as u8;
+    let count = count.max(1);
+    [
+        unpremultiply(r),
+        unpremultiply(g),
+        unpremultiply(b),
+        ((a + count / 2) / count).min(255) as u8,
+    ]
+}
+
+fn add(sum: &mut [u64; 4], pixel: [u64; 4]) {
+    for (total, channel) in sum.iter_mut().zip(pixel) {
+        *total += channel;
+    }
+}
+
+fn pixelate(pixels: &[[u8; 4]], size: UVec2, block: usize) -> Vec<[u8; 4]> {
+    let width = size.x as usize;
+    let height = size.y as usize;
+    let mut result = vec![[0; 4]; pixels.len()];
+
+    for block_y in (0..height).step_by(block) {
+        for block_x in (0..width).step_by(block) {
+            let cells = || {
+                (block_y..(block_y + block).min(height)).flat_map(move |y| {
+                    (block_x..(block_x + block).min(width)).map(move |x| y * width + x)
+                })
+            };
+
+            let mut sum = [0; 4];
+            let mut count = 0;
+            for pixel in cells().filter_map(|i| pixels.get(i)) {
+                add(&mut sum, premultiply(*pixel));
+                count += 1;
+            }
+
+            let color = average(sum, count);
+            for i in cells() {
+                if let Some(pixel) = result.get_mut(i) {
+                    *pixel = color;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn blur(pixels: &[[u8; 4]], size: UVec2, radius: usize) -> Vec<[u8; 4]> {
+    let width = (size.x as usize).max(1);
+    let height = size.y as usize;
+    let radius = radius as isize;
+
+    (0..pixels.len())
+        .map(|i| {
+            let (x, y) = ((i % width) as isize, (i / width) as isize);
+            let mut sum = [0; 4];
+            let mut count = 0;
+            for ny in (y - radius).max(0)..=(y + radius).min(height as isize - 1) {
+                for nx in (x - radius).max(0)..=(x + radius).min(width as isize - 1) {
+                    if let Some(pixel) = pixels.get(ny as usize * width + nx as usize) {
+                        add(&mut sum, premultiply(*pixel));
+                        count += 1;
+                    }
+                }
+            }
+            average(sum, count)
+        })
+        .collect()
+}
+
+fn reveal(pixels: &[[u8; 4]], cell_size: UVec2, mask: RevealMask, revealed: u8) -> Vec<[u8; 4]> {
+    let width = (cell_size.x as usize).max(1);
+    let size = cell_size.as_vec2();
+    let threshold = revealed as f32 / 255.0;
+
+    // Random order in which tiles are uncovered, one value per tile in 0..1
+    let tile_order: Vec<f32> = match mask {
+        RevealMask::Tiles { size: tile, seed } => {
+            let tile = tile.max(1) as u32;
+            let tiles = cell_size.x.div_ceil(tile) * cell_size.y.div_ceil(tile);
+            let mut rng = fastrand::Rng::with_seed(seed);
+            (0..tiles).map(|_| rng.f32()).collect()
+        }
+        _ => Vec::new(),
+    };
+
+    pixels
+        .iter()
+        .enumerate()
+        .map(|(i, &pixel)| {
+            let position = Vec2::new((i % width) as f32 + 0.5, (i / width) as f32 + 0.5);
+            let order = match mask {
+                RevealMask::LeftToRight => position.x / size.x,
+                RevealMask::TopToBottom => position.y / size.y,
+                RevealMask::Radial => position.distance(size * 0.5) / (size * 0.5).length(),
+                RevealMask::Tiles { size: tile, .. } => {
+                    let tile = tile.max(1) as usize;
+                    let columns = width.div_ceil(tile);
+                    let tile_index = (i / width / tile) * columns + (i % width) / tile;
+                    tile_order.get(tile_index).copied().unwrap_or(1.0)
+                }
+            };
+
+            if order < threshold { pixel } else { [0; 4] }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::super::test_utils::{analyze, atlas, cell, emoji_world};
+    use super::*;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const CLEAR: [u8; 4] = [0; 4];
+
+    /// 2×2 image: an opaque and a translucent pixel above a clear and a white one
+    const PIXELS: [[u8; 4]; 4] = [[200, 100, 50, 255], [10, 20, 30, 128], CLEAR, [255; 4]];
+    const SIZE: UVec2 = UVec2::new(2, 2);
+
+    fn assert_close(actual: [u8; 4], expected: [u8; 4]) {
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(&a, b)| a.abs_diff(b) <= 1),
+            "{actual:?} is not {expected:?}"
+        );
+    }
+
+    #[test]
+    fn paints_silhouettes_and_tints() {
+        assert_eq!(
+            EmojiVariant::Silhouette.apply(&PIXELS, SIZE),
+            [[0, 0, 0, 255], [0, 0, 0, 128], CLEAR, [0, 0, 0, 255]]
+        );
+        assert_eq!(
+            EmojiVariant::Tint([255, 0, 128]).apply(&PIXELS, SIZE),
+            [
+                [200, 0, 25, 255],
+                [10, 0, 15, 128],
+                CLEAR,
+                [255, 0, 128, 255]
+            ]
+        );
+        assert_eq!(
+            EmojiVariant::tint(Color::srgb(1.0, 0.0, 0.0)),
+            EmojiVariant::Tint([255, 0, 0])
+        );
+    }
+
+    #[test]
+    fn shifts_hues() {
+        let shifted = EmojiVariant::HueShift(120).apply(&[RED, [255; 4]], UVec2::new(2, 1));
+        let [red, white] = shifted[..] else {
+            panic!("Shifting keeps the pixel count");
+        };
+        assert_close(red, [0, 255, 0, 255]);
+        assert_close(white, [255; 4]);
+        assert_eq!(EmojiVariant::hue_shift(-90.0), EmojiVariant::HueShift(270));
+    }
+
+    #[test]
+    fn pixelates_without_darkening_edges() {
+        let pixelated = EmojiVariant::Pixelate(2).apply(&[RED, CLEAR, CLEAR, CLEAR], SIZE);
+        assert_eq!(pixelated, [[255, 0, 0, 64]; 4]);
+        assert_eq!(EmojiVariant::Pixelate(1).apply(&PIXELS, SIZE), PIXELS);
+        assert_eq!(EmojiVariant::Pixelate(0).apply(&PIXELS, SIZE), PIXELS);
+    }
+
+    #[test]
+    fn blurs_neighbors() {
+        let size = UVec2::new(3, 1);
+        let blurred = EmojiVariant::Blur(1).apply(&[RED, CLEAR, CLEAR], size);
+        assert_eq!(blurred, [[255, 0, 0, 128], [255, 0, 0, 85], CLEAR]);
+        assert_eq!(EmojiVariant::Blur(0).apply(&PIXELS, SIZE), PIXELS);
+    }
+
+    #[test]
+    fn mirrors_rows() {
+        assert_eq!(
+            EmojiVariant::Mirrored.apply(&PIXELS, SIZE),
+            [PIXELS[1], PIXELS[0], PIXELS[3], PIXELS[2]]
+        );
+    }
+
+    #[test]
+    fn reveals_following_the_mask() {
+        let revealed = |mask: RevealMask, fraction: f32, size: UVec2| {
+            let pixels = vec![RED; (size.x * size.y) as usize];
+            EmojiVariant::reveal(mask, fraction)
+                .apply(&pixels, size)
+                .iter()
+                .map(|&pixel| pixel == RED)
+                .collect::<Vec<bool>>()
+        };
+
+        let row = UVec2::new(4, 1);
+        assert_eq!(
+            revealed(RevealMask::LeftToRight, 0.5, row),
+            [true, true, false, false]
+        );
+        assert_eq!(
+            revealed(RevealMask::TopToBottom, 0.5, UVec2::new(1, 4)),
+            [true, true, false, false]
+        );
+        assert_eq!(revealed(RevealMask::LeftToRight, 0.0, row), [false; 4]);
+        assert_eq!(revealed(RevealMask::LeftToRight, 1.0, row), [true; 4]);
+        assert_eq!(
+            revealed(RevealMask::Radial, 0.2, UVec2::new(3, 3)),
+            [false, false, false, false, true, false, false, false, false]
+        );
+
+        let tiles = RevealMask::Tiles { size: 2, seed: 7 };
+        let square = UVec2::new(4, 4);
+        let tiled = revealed(tiles, 0.5, square);
+        for tile in [0, 2, 8, 10] {
+            let pixels = [tile, tile + 1, tile + 4, tile + 5].map(|i| tiled.get(i).copied());
+            assert!(
+                pixels.iter().all(|&pixel| pixel == pixels[0]),
+                "tile {tile} is partially revealed"
+            );
+        }
+        assert_eq!(tiled, revealed(tiles, 0.5, square), "the order is seeded");
+        assert_eq!(revealed(tiles, 1.0, square), [true; 16]);
+
+        assert_eq!(
+            EmojiVariant::reveal(RevealMask::Radial, 2.0),
+            EmojiVariant::Reveal {
+                mask: RevealMask::Radial,
+                revealed: 255
+            }
+        );
+    }
+
+    #[test]
+    fn caches_variants_per_emoji() {
+        let (mut atlas, images) = atlas(
+            &[
+                cell(SIZE, [255; 3], |_, _| true),
+                cell(SIZE, [0; 3], |x, _| x == 0),
+            ],
+            SIZE,
+        );
+        let validation = analyze(&mut atlas, &images);
+        let mut world = emoji_world(atlas, validation);
+        world.insert_resource(images);
+        world.init_resource::<EmojiVariantCache>();
+
+        world
+            .run_system_once(|mut variants: EmojiVariants| {
+                let mut image = |emoji: usize, variant| {
+                    variants
+                        .image(emoji, variant)
+                        .expect("The emoji is in the atlas")
+                };
+                let silhouette = image(0, EmojiVariant::Silhouette);
+                assert_eq!(silhouette, image(0, EmojiVariant::Silhouette));
+                assert_ne!(silhouette, image(1, EmojiVariant::Silhouette));
+                assert_ne!(silhouette, image(0, EmojiVariant::Mirrored));
+                assert_eq!(variants.cache.images.len(), 3);
+
+                let pixels = &variants
+                    .images
+                    .get(&silhouette)
+                    .expect("The variant is stored")
+                    .data;
+                assert!(pixels.chunks(4).all(|pixel| pixel == [0, 0, 0, 255]));
+
+                assert!(matches!(
+                    variants.image(2, EmojiVariant::Silhouette),
+                    Err(AtlasError::InvalidIndex(2))
+                ));
+                variants.cache.clear();
+                assert!(variants.cache.images.is_empty());
+            })
+            .expect("The system runs");
+    }
+}
@@ -0,0 +1,583 @@
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::FONT;
+use crate::i18n::Strings;
+
+const HUD_MARGIN: f32 = 12.0;
+const HUD_FONT_SIZE: f32 = 24.0;
+const LIFE_SIZE: f32 = 16.0;
+/// Side in pixels of the heart image drawn for each life
+const HEART_RESOLUTION: u32 = 32;
+const COUNTDOWN_HEIGHT: f32 = 8.0;
+const COMBO_METER_SIZE: Vec2 = Vec2::new(80.0, 6.0);
+/// Below this fraction of time left the countdown bar turns to [`LOW_TIME_COLOR`]
+const LOW_TIME: f32 = 0.25;
+/// How fast the score counter catches up with the score, per second
+const SCORE_EASING: f32 = 10.0;
+
+const LIFE_COLOR: Color = Color::srgb(0.9, 0.2, 0.3);
+const LOST_LIFE_COLOR: Color = Color::srgba(0.3, 0.3, 0.3, 0.6);
+const TIME_COLOR: Color = Color::srgb(0.0, 1.0, 0.0);
+const LOW_TIME_COLOR: Color = Color::srgb(1.0, 0.2, 0.0);
+const METER_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.3);
+const COMBO_COLOR: Color = Color::srgb(1.0, 0.8, 0.0);
+
+/// Heads-up display widgets laid out the same way in every bit
+///
+/// Each widget reads a bit's resource through a small trait, and only the widgets
+/// added are spawned:
+///
+/// ```ignore
+/// impl HudScore for ScoreState {
+///     fn score(&self) -> u32 {
+///         self.total_score
+///     }
+/// }
+///
+/// app.add_plugins(
+///     HudPlugin::new()
+///         .with_score::<ScoreState>()
+///         .with_lives::<GameProgress>()
+///         .in_state(GameState::Playing),
+/// );
+/// ```
+///
+/// The score sits top left with the combo meter under it, lives top center, the stage
+/// top right and the countdown bar along the top edge.
+#[derive(Default)]
+pub struct HudPlugin {
+    widgets: Vec<fn(&mut App)>,
+    visibility: Option<Box<dyn Fn(&mut App) + Send + Sync>>,
+}
+
+impl HudPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Animated score counter
+    #[must_use]
+    pub fn with_score<S: HudScore>(mut self) -> Self {
+        self.widgets.push(|app| {
+            app.add_systems(Startup, spawn_score)
+                .add_systems(Update, update_score::<S>);
+        });
+        self
+    }
+
+    /// Row of hearts, lost lives greyed out
+    #[must_use]
+    pub fn with_lives<L: HudLives>(mut self) -> Self {
+        self.widgets.push(|app| {
+            app.add_systems(Startup, spawn_lives)
+                .add_systems(Update, update_lives::<L>);
+        });
+        self
+    }
+
+    /// Bar shrinking with the time left
+    #[must_use]
+    pub fn with_countdown<C: HudCountdown>(mut self) -> Self {
+        self.widgets.push(|app| {
+            app.add_systems(Startup, spawn_countdown)
+                .add_systems(Update, update_countdown::<C>);
+        });
+        self
+    }
+
+    /// Stage label
+    #[must_use]
+    pub fn with_stage<S: HudStage>(mut self) -> Self {
+        self.widgets.push(|app| {
+            app.add_systems(Startup, spawn_stage)
+                .add_systems(Update, update_stage::<S>);
+        });
+        self
+    }
+
+    /// Combo multiplier with a meter, shown from a combo of 2
+    #[must_use]
+    pub fn with_combo<C: HudCombo>(mut self) -> Self {
+        self.widgets.push(|app| {
+            app.add_systems(Startup, spawn_combo)
+                .add_systems(Update, update_combo::<C>);
+        });
+        self
+    }
+
+    /// Only shows the HUD in `state`, it is always shown otherwise
+    #[must_use]
+    pub fn in_state<S: States>(mut self, state: S) -> Self {
+        self.visibility = Some(Box::new(move |app| {
+            app.add_systems(OnEnter(state.clone()), |mut visible: ResMut<HudVisible>| {
+                visible.0 = true;
+            })
+            .add_systems(OnExit(state.clone()), |mut visible: ResMut<HudVisible>| {
+                visible.0 = false;
+            });
+        }));
+        self
+    }
+}
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HudVisible(self.visibility.is_none()))
+            .add_systems(Update, apply_hud_visibility);
+
+        if let Some(visibility) = &self.visibility {
+            visibility(app);
+        }
+        for widget in &self.widgets {
+            widget(app);
+        }
+    }
+}
+
+pub trait HudScore: Resource {
+    fn score(&self) -> u32;
+}
+
+pub trait HudLives: Resource {
+    fn lives(&self) -> u32;
+    fn max_lives(&self) -> u32;
+}
+
+pub trait HudCountdown: Resource {
+    /// Fraction of the time left, from 1 at the start to 0
+    fn remaining(&self) -> f32;
+}
+
+pub trait HudStage: Resource {
+    fn stage(&self) -> u32;
+}
+
+pub trait HudCombo: Resource {
+    fn combo(&self) -> u32;
+    /// Fill of the meter from 0 to 1, e.g. the time left before the combo breaks
+    fn meter(&self) -> f32;
+}
+
+/// Whether the HUD is shown, set by [`HudPlugin::in_state`] or by the bit itself
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HudVisible(pub bool);
+
+/// Root node of a HUD widget
+#[derive(Component)]
+pub struct HudWidget;
+
+#[derive(Component)]
+struct ScoreCounter {
+    shown: f32,
+}
+
+#[derive(Component)]
+struct LivesRow {
+    shown: Option<(u32, u32)>,
+    /// White heart, tinted for each life
+    heart: Handle<Image>,
+}
+
+#[derive(Component)]
+struct CountdownFill;
+
+#[derive(Component)]
+struct StageLabel;
+
+#[derive(Component)]
+struct ComboLabel;
+
+#[derive(Component)]
+struct ComboMeter;
+
+#[derive(Component)]
+struct ComboFill;
+
+fn apply_hud_visibility(
+    visible: Res<HudVisible>,
+    mut widgets: Query<(&mut Visibility, Ref<HudWidget>)>,
+) {
+    for (mut visibility, widget) in &mut widgets {
+        if visible.is_changed() || widget.is_added() {
+            *visibility = if visible.0 {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+fn hud_font(asset_server: &AssetServer) -> TextFont {
+    TextFont {
+        font: asset_server.load(FONT),
+        font_size: HUD_FONT_SIZE,
+        ..default()
+    }
+}
+
+fn spawn_score(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        HudWidget,
+        ScoreCounter { shown: 0.0 },
+        Text::default(),
+        hud_font(&asset_server),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(HUD_MARGIN),
+            left: Val::Px(HUD_MARGIN),
+            ..default()
+        },
+    ));
+}
+
+fn update_score<S: HudScore>(
+    source: Option<Res<S>>,
+    time: Res<Time>,
+    strings: Strings,
+    mut counters: Query<(&mut Text, &mut ScoreCounter)>,
+) {
+    let Some(source) = source else {
+        return;
+    };
+    let score = source.score();
+
+    for (mut text, mut counter) in &mut counters {
+        counter.shown = ease_score(counter.shown, score, time.delta_secs());
+        let label = strings.format("hud.score", &[("score", (counter.shown as u32).into())]);
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
+
+/// Moves the shown score towards the actual one, dropping at once when the score went down
+fn ease_score(shown: f32, score: u32, delta_secs: f32) -> f32 {
+    let target = score as f32;
+    if shown >= target {
+        return target;
+    }
+
+    let eased = (target - shown).mul_add(1.0 - (-SCORE_EASING * delta_secs).exp(), shown);
+    if target - eased < 1.0 { target } else { eased }
+}
+
+fn spawn_lives(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.spawn((
+        HudWidget,
+        LivesRow {
+            shown: None,
+            heart: images.add(heart_image()),
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(HUD_MARGIN + (HUD_FONT_SIZE - LIFE_SIZE) / 2.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            column_gap: Val::Px(LIFE_SIZE / 4.0),
+            ..default()
+        },
+    ));
+}
+
+fn update_lives<L: HudLives>(
+    mut commands: Commands,
+    source: Option<Res<L>>,
+    mut rows: Query<(Entity, &mut LivesRow)>,
+) {
+    let Some(source) = source else {
+        return;
+    };
+    let lives = (source.lives(), source.max_lives());
+
+    for (entity, mut row) in &mut rows {
+        if row.shown == Some(lives) {
+            continue;
+        }
+        row.shown = Some(lives);
+
+        let (remaining, max) = lives;
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for life in 0..max {
+                    parent.spawn((
+                        Node {
+                            width: Val::Px(LIFE_SIZE),
+                            height: Val::Px(LIFE_SIZE),
+                            ..default()
+                        },
+                        ImageNode::new(row.heart.clone()).with_color(if life < remaining {
+                            LIFE_COLOR
+                        } else {
+                            LOST_LIFE_COLOR
+                        }),
+                    ));
+                }
+            });
+    }
+}
+
+/// White heart on a transparent background, with antialiased edges
+fn heart_image() -> Image {
+    // Samples per pixel side, for the antialiasing
+    const SAMPLES: u32 = 4;
+
+    let size = HEART_RESOLUTION;
+    let data = (0..size * size)
+        .flat_map(|i| {
+            let pixel = UVec2::new(i % size, i / size).as_vec2();
+            let covered = (0..SAMPLES * SAMPLES)
+                .filter(|sample| {
+                    let offset = (UVec2::new(sample % SAMPLES, sample / SAMPLES).as_vec2() + 0.5)
+                        / SAMPLES as f32;
+                    is_in_heart((pixel + offset) / size as f32)
+                })
+                .count();
+            let alpha = (covered * 255 / (SAMPLES * SAMPLES) as usize) as u8;
+            [255, 255, 255, alpha]
+        })
+        .collect();
+
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Returns whether a point of the image, in `0..1` from the top left, is in the heart
+fn is_in_heart(uv: Vec2) -> bool {
+    // The curve (x² + y² - 1)³ = x²y³ spans about -1.14..1.14 by -1..1.24, centered
+    // in a 2.4 wide square
+    let x = (uv.x - 0.5) * 2.4;
+    let y = uv.y.mul_add(-2.4, 1.32);
+    let radius = x.mul_add(x, y * y) - 1.0;
+    radius.powi(3) <= x * x * y.powi(3)
+}
+
+fn spawn_countdown(mut commands: Commands) {
+    commands
+        .spawn((
+            HudWidget,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Px(COUNTDOWN_HEIGHT),
+                ..default()
+            },
+            BackgroundColor(METER_BACKGROUND),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                CountdownFill,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(TIME_COLOR),
+            ));
+        });
+}
+
+fn update_countdown<C: HudCountdown>(
+    source: Option<Res<C>>,
+    mut fills: Query<(&mut Node, &mut BackgroundColor), With<CountdownFill>>,
+) {
+    let Some(source) = source else {
+        return;
+    };
+    let remaining = source.remaining().clamp(0.0, 1.0);
+
+    let width = Val::Percent(remaining * 100.0);
+    let fill = BackgroundColor(if remaining < LOW_TIME {
+        LOW_TIME_COLOR
+    } else {
+        TIME_COLOR
+    });
+    // Only written when it changes, the UI being laid out again after every change
+    for (mut node, mut color) in &mut fills {
+        if node.width != width {
+            node.width = width;
+        }
+        color.set_if_neq(fill);
+    }
+}
+
+fn spawn_stage(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        HudWidget,
+        StageLabel,
+        Text::default(),
+        hud_font(&asset_server),
+        TextLayout::new_with_justify(JustifyText::Right),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(HUD_MARGIN),
+            right: Val::Px(HUD_MARGIN),
+            ..default()
+        },
+    ));
+}
+
+fn update_stage<S: HudStage>(
+    source: Option<Res<S>>,
+    strings: Strings,
+    mut labels: Query<&mut Text, With<StageLabel>>,
+) {
+    let Some(source) = source else {
+        return;
+    };
+    let label = strings.format("hud.stage", &[("stage", source.stage().into())]);
+
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0.clone_from(&label);
+        }
+    }
+}
+
+fn spawn_combo(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            HudWidget,
+            ComboMeter,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(HUD_MARGIN.mul_add(2.0, HUD_FONT_SIZE)),
+                left: Val::Px(HUD_MARGIN),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(COMBO_METER_SIZE.y / 2.0),
+                display: Display::None,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ComboLabel,
+                Text::default(),
+                hud_font(&asset_server),
+                TextColor(COMBO_COLOR),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(COMBO_METER_SIZE.x),
+                        height: Val::Px(COMBO_METER_SIZE.y),
+                        ..default()
+                    },
+                    BackgroundColor(METER_BACKGROUND),
+                ))
+                .with_children(|meter| {
+                    meter.spawn((
+                        ComboFill,
+                        Node {
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(COMBO_COLOR),
+                    ));
+                });
+        });
+}
+
+fn update_combo<C: HudCombo>(
+    source: Option<Res<C>>,
+    strings: Strings,
+    mut meters: Query<&mut Node, (With<ComboMeter>, Without<ComboFill>)>,
+    mut labels: Query<&mut Text, With<ComboLabel>>,
+    mut fills: Query<&mut Node, (With<ComboFill>, Without<ComboMeter>)>,
+) {
+    let Some(source) = source else {
+        return;
+    };
+    let combo = source.combo();
+
+    let display = if combo >= 2 {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut node in &mut meters {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    let label = strings.format("hud.combo", &[("combo", combo.into())]);
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0.clone_from(&label);
+        }
+    }
+
+    let width = Val::Percent(source.meter().clamp(0.0, 1.0) * 100.0);
+    for mut node in &mut fills {
+        if node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_counter_catches_up() {
+        let mut shown = 0.0;
+        for _ in 0..10 {
+            let next = ease_score(shown, 1000, 1.0 / 60.0);
+            assert!(next > shown, "the counter keeps counting up");
+            shown = next;
+        }
+        assert!(shown < 1000.0, "the counter takes a moment");
+
+        for _ in 0..120 {
+            shown = ease_score(shown, 1000, 1.0 / 60.0);
+        }
+        assert!(shown >= 1000.0, "the counter lands on the score");
+    }
+
+    #[test]
+    fn draws_a_heart() {
+        assert!(is_in_heart(Vec2::new(0.5, 0.5)), "the center is filled");
+        assert!(
+            is_in_heart(Vec2::new(0.25, 0.25)),
+            "the left lobe is filled"
+        );
+        assert!(
+            is_in_heart(Vec2::new(0.75, 0.25)),
+            "the right lobe is filled"
+        );
+        assert!(is_in_heart(Vec2::new(0.5, 0.9)), "the tip is filled");
+        assert!(!is_in_heart(Vec2::new(0.5, 0.05)), "the top has a notch");
+        assert!(!is_in_heart(Vec2::new(0.05, 0.95)), "the corners are empty");
+        assert!(!is_in_heart(Vec2::new(0.95, 0.95)), "the corners are empty");
+
+        let image = heart_image();
+        assert_eq!(image.width(), HEART_RESOLUTION);
+        let alphas: Vec<u8> = image.data.iter().skip(3).step_by(4).copied().collect();
+        assert!(alphas.contains(&255), "the inside is opaque");
+        assert!(alphas.contains(&0), "the outside is transparent");
+    }
+
+    #[test]
+    fn score_counter_drops_at_once() {
+        assert!(
+            ease_score(500.0, 0, 1.0 / 60.0) < 1.0,
+            "a reset score is shown right away"
+        );
+    }
+}
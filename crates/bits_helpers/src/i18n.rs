@@ -0,0 +1,611 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::launch_options::launch_option;
+
+/// Locales with a string table in `assets/i18n`, the first one being the fallback
+pub const SUPPORTED_LOCALES: &[&str] = &["en", "fr"];
+
+pub const STRINGS_DIR: &str = "bits://i18n";
+
+/// English strings, built into the binary so that text never waits for an asset
+const FALLBACK_STRINGS: &str = include_str!("../assets/i18n/en.strings.ron");
+
+/// Translates user-facing strings through per-locale string tables
+///
+/// Tables live in `assets/i18n/<locale>.strings.ron` and map keys to either a string
+/// or plural forms:
+///
+/// ```ron
+/// {
+///     "game_over.title": "Game Over!",
+///     "hud.lives": (one: "{count} life left", other: "{count} lives left"),
+/// }
+/// ```
+///
+/// Strings may contain `{name}` placeholders, and `{name:.N}` to show a decimal
+/// number with `N` digits. Numbers are formatted for the current locale.
+pub struct I18nPlugin;
+
+impl Plugin for I18nPlugin {
+    fn build(&self, app: &mut App) {
+        let fallback = match ron::from_str::<StringTableFile>(FALLBACK_STRINGS) {
+            Ok(file) => file.into(),
+            Err(err) => {
+                error!("Could not parse fallback strings: {err}");
+                StringTable::default()
+            }
+        };
+
+        if !app.world().contains_resource::<Locale>() {
+            app.insert_resource(Locale::from_launch_options());
+        }
+
+        app.init_asset::<StringTable>()
+            .init_asset_loader::<StringTableLoader>()
+            .insert_resource(FallbackStrings(fallback))
+            .init_resource::<LocaleStrings>()
+            .add_systems(
+                PreUpdate,
+                load_locale_strings.run_if(resource_changed::<Locale>),
+            );
+    }
+}
+
+/// Language the bit is displayed in
+///
+/// Set from the launch options when the app starts, and can be changed at runtime.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale(&'static str);
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self(Self::FALLBACK)
+    }
+}
+
+impl Locale {
+    pub const FALLBACK: &'static str = "en";
+
+    /// Picks the supported locale closest to a language tag such as `fr-CA` or `pt_BR`,
+    /// falling back to English
+    pub fn resolve(tag: &str) -> Self {
+        let tag = tag.trim().replace('_', "-");
+        let language = tag.split('-').next().unwrap_or_default();
+
+        SUPPORTED_LOCALES
+            .iter()
+            .find(|locale| locale.eq_ignore_ascii_case(&tag))
+            .or_else(|| {
+                SUPPORTED_LOCALES
+                    .iter()
+                    .find(|locale| locale.eq_ignore_ascii_case(language))
+            })
+            .map_or_else(Self::default, |locale| Self(locale))
+    }
+
+    /// Reads the locale the bit was launched with
+    ///
+    /// On wasm, from the `locale` query parameter, then the browser language.
+    /// On native, from the `BITS_LOCALE` environment variable, then `LANG`.
+    pub fn from_launch_options() -> Self {
+        launch_locale().map_or_else(Self::default, |tag| Self::resolve(&tag))
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        self.0
+    }
+
+    fn plural_category(self, count: i64) -> PluralCategory {
+        match self.0 {
+            "fr" if count.abs() <= 1 => PluralCategory::One,
+            "fr" => PluralCategory::Other,
+            _ if count.abs() == 1 => PluralCategory::One,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    /// Thousands separator and decimal mark
+    const fn number_format(self) -> (&'static str, char) {
+        match self.0.as_bytes() {
+            b"fr" => ("\u{202f}", ','),
+            _ => (",", '.'),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn launch_locale() -> Option<String> {
+    let tag = launch_option("locale").or_else(|| std::env::var("LANG").ok())?;
+    // LANG looks like en_US.UTF-8
+    let tag = tag.split('.').next()?.to_string();
+    (!tag.is_empty() && tag != "C" && tag != "POSIX").then_some(tag)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn launch_locale() -> Option<String> {
+    launch_option("locale").or_else(|| web_sys::window()?.navigator().language())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PluralCategory {
+    One,
+    Other,
+}
+
+/// A translated string, or its plural forms
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum StringEntry {
+    Text(String),
+    Plural {
+        /// Used for a count of exactly 0 when present
+        #[serde(default)]
+        zero: Option<String>,
+        #[serde(default)]
+        one: Option<String>,
+        other: String,
+    },
+}
+
+impl StringEntry {
+    fn select(&self, locale: Locale, count: Option<i64>) -> &str {
+        match (self, count) {
+            (Self::Text(text), _) => text,
+            (
+                Self::Plural {
+                    zero: Some(zero), ..
+                },
+                Some(0),
+            ) => zero,
+            (Self::Plural { one, other, .. }, Some(count))
+                if locale.plural_category(count) == PluralCategory::One =>
+            {
+                one.as_deref().unwrap_or(other)
+            }
+            (Self::Plural { other, .. }, _) => other,
+        }
+    }
+
+    /// Every form of the entry, e.g. to check placeholders
+    pub fn forms(&self) -> impl Iterator<Item = &str> {
+        let (text, zero, one) = match self {
+            Self::Text(text) => (text, None, None),
+            Self::Plural { zero, one, other } => (other, zero.as_ref(), one.as_ref()),
+        };
+        core::iter::once(text)
+            .chain(zero)
+            .chain(one)
+            .map(String::as_str)
+    }
+}
+
+/// Strings of a single locale, keyed by `<bit or module>.<name>`
+#[derive(Asset, TypePath, Debug, Default)]
+pub struct StringTable {
+    entries: HashMap<String, StringEntry>,
+}
+
+impl StringTable {
+    pub fn get(&self, key: &str) -> Option<&StringEntry> {
+        self.entries.get(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct StringTableFile(HashMap<String, StringEntry>);
+
+impl From<StringTableFile> for StringTable {
+    fn from(file: StringTableFile) -> Self {
+        Self { entries: file.0 }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum StringTableLoaderError {
+    #[error("Could not read string table: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not parse string table: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[derive(Default)]
+struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    type Asset = StringTable;
+    type Settings = ();
+    type Error = StringTableLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file: StringTableFile = ron::de::from_bytes(&bytes)?;
+        Ok(file.into())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["strings.ron"]
+    }
+}
+
+#[derive(Resource)]
+struct FallbackStrings(StringTable);
+
+/// Table of the current locale, `None` when it is the fallback
+#[derive(Resource, Default)]
+struct LocaleStrings(Option<Handle<StringTable>>);
+
+fn load_locale_strings(
+    locale: Res<Locale>,
+    mut strings: ResMut<LocaleStrings>,
+    asset_server: Res<AssetServer>,
+) {
+    strings.0 = (locale.as_str() != Locale::FALLBACK)
+        .then(|| asset_server.load(format!("{STRINGS_DIR}/{}.strings.ron", locale.as_str())));
+}
+
+/// Value substituted into a `{name}` placeholder
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringArg {
+    Text(String),
+    Integer(i64),
+    Decimal(f64),
+}
+
+impl From<&str> for StringArg {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for StringArg {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+macro_rules! integer_arg {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for StringArg {
+            fn from(value: $ty) -> Self {
+                Self::Integer(value as i64)
+            }
+        })*
+    };
+}
+integer_arg!(i32, i64, u32, u64, usize);
+
+impl From<f32> for StringArg {
+    fn from(value: f32) -> Self {
+        Self::Decimal(value as f64)
+    }
+}
+
+impl From<f64> for StringArg {
+    fn from(value: f64) -> Self {
+        Self::Decimal(value)
+    }
+}
+
+/// System parameter translating strings into the current locale
+#[derive(SystemParam)]
+pub struct Strings<'w> {
+    locale: Res<'w, Locale>,
+    fallback: Res<'w, FallbackStrings>,
+    current: Res<'w, LocaleStrings>,
+    tables: Res<'w, Assets<StringTable>>,
+    asset_server: Res<'w, AssetServer>,
+}
+
+impl Strings<'_> {
+    pub fn locale(&self) -> Locale {
+        *self.locale
+    }
+
+    /// Returns whether the current locale table is loaded, English being always ready
+    ///
+    /// A table that failed to load counts as ready, its strings falling back to English.
+    pub fn is_ready(&self) -> bool {
+        self.current.0.as_ref().is_none_or(|handle| {
+            self.tables.contains(handle) || self.asset_server.load_state(handle).is_failed()
+        })
+    }
+
+    /// Translates a string without placeholders
+    pub fn get(&self, key: &str) -> String {
+        self.format(key, &[])
+    }
+
+    /// Translates a string, substituting its `{name}` placeholders
+    pub fn format(&self, key: &str, args: &[(&str, StringArg)]) -> String {
+        self.translate(key, None, args)
+    }
+
+    /// Translates a string with plural forms, `{count}` being available as a placeholder
+    pub fn plural(&self, key: &str, count: i64, args: &[(&str, StringArg)]) -> String {
+        self.translate(key, Some(count), args)
+    }
+
+    fn translate(&self, key: &str, count: Option<i64>, args: &[(&str, StringArg)]) -> String {
+        let entry = self
+            .current
+            .0
+            .as_ref()
+            .and_then(|handle| self.tables.get(handle))
+            .and_then(|table| table.get(key))
+            .or_else(|| self.fallback.0.get(key));
+
+        let Some(entry) = entry else {
+            warn!("Missing string {key:?}");
+            return key.to_string();
+        };
+
+        let count_arg = count.map(|count| ("count", StringArg::Integer(count)));
+        let args: Vec<(&str, StringArg)> = args.iter().cloned().chain(count_arg).collect();
+        format_string(entry.select(*self.locale, count), &args, *self.locale)
+    }
+}
+
+/// Substitutes `{name}` and `{name:.N}` placeholders, leaving unknown ones untouched
+fn format_string(template: &str, args: &[(&str, StringArg)], locale: Locale) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(rest.get(..start).unwrap_or_default());
+        let placeholder = rest.get(start..).unwrap_or_default();
+
+        let Some(end) = placeholder.find('}') else {
+            // Never closed, the rest is plain text
+            result.push_str(placeholder);
+            return result;
+        };
+        let inner = placeholder.get(1..end).unwrap_or_default();
+        let (name, precision) = match inner.split_once(":.") {
+            Some((name, precision)) => (name, precision.parse().ok()),
+            None => (inner, None),
+        };
+
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => result.push_str(&format_arg(value, precision, locale)),
+            None => result.push_str(placeholder.get(..=end).unwrap_or_default()),
+        }
+        rest = placeholder.get(end + 1..).unwrap_or_default();
+    }
+
+    result.push_str(rest);
+    result
+}
+
+fn format_arg(value: &StringArg, precision: Option<usize>, locale: Locale) -> String {
+    match value {
+        StringArg::Text(text) => text.clone(),
+        StringArg::Integer(value) => format_number(&value.to_string(), locale),
+        StringArg::Decimal(value) => format_number(
+            &precision.map_or_else(
+                || value.to_string(),
+                |precision| format!("{value:.precision$}"),
+            ),
+            locale,
+        ),
+    }
+}
+
+/// Groups the integer digits by thousands and localizes the decimal mark
+fn format_number(number: &str, locale: Locale) -> String {
+    let (separator, decimal_mark) = locale.number_format();
+    let (sign, number) = number
+        .strip_prefix('-')
+        .map_or(("", number), |number| ("-", number));
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    let mut grouped = String::from(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    if !fraction.is_empty() {
+        grouped.push(decimal_mark);
+        grouped.push_str(fraction);
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_table(locale: &str) -> StringTable {
+        let path = format!(
+            "{}/assets/i18n/{locale}.strings.ron",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let text = std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("{path}: {err}"));
+        ron::from_str::<StringTableFile>(&text)
+            .unwrap_or_else(|err| panic!("{path}: {err}"))
+            .into()
+    }
+
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = text
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}'))
+            .map(|(inner, _)| inner.split(':').next().unwrap_or_default())
+            .filter(|name| *name != "count")
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    #[test]
+    fn every_locale_translates_every_key() {
+        let fallback = read_table(Locale::FALLBACK);
+        let mut problems = Vec::new();
+
+        for &locale in SUPPORTED_LOCALES.iter().filter(|&&l| l != Locale::FALLBACK) {
+            let table = read_table(locale);
+            for key in fallback.keys() {
+                let (Some(expected), Some(translated)) = (fallback.get(key), table.get(key)) else {
+                    problems.push(format!("{locale}: missing {key:?}"));
+                    continue;
+                };
+                for form in translated.forms() {
+                    let expected_names =
+                        expected.forms().flat_map(placeholders).collect::<Vec<_>>();
+                    if placeholders(form)
+                        .iter()
+                        .any(|name| !expected_names.contains(name))
+                    {
+                        problems.push(format!("{locale}: unknown placeholder in {key:?}"));
+                    }
+                }
+            }
+            for key in table.keys() {
+                if fallback.get(key).is_none() {
+                    problems.push(format!("{locale}: {key:?} is not in the fallback table"));
+                }
+            }
+        }
+
+        assert!(
+            problems.is_empty(),
+            "Untranslated strings:\n{}",
+            problems.join("\n")
+        );
+    }
+
+    #[test]
+    fn fallback_table_is_embedded() {
+        assert!(
+            ron::from_str::<StringTableFile>(FALLBACK_STRINGS).is_ok(),
+            "The embedded fallback strings should parse"
+        );
+    }
+
+    #[test]
+    fn resolves_locales() {
+        assert_eq!(
+            Locale::resolve("fr-CA"),
+            Locale("fr"),
+            "Regional tags use their language"
+        );
+        assert_eq!(
+            Locale::resolve("FR"),
+            Locale("fr"),
+            "Tags are case insensitive"
+        );
+        assert_eq!(
+            Locale::resolve("de_DE"),
+            Locale("en"),
+            "Unknown languages fall back"
+        );
+    }
+
+    #[test]
+    #[expect(
+        clippy::literal_string_with_formatting_args,
+        reason = "templates use the same braces as format strings"
+    )]
+    fn formats_placeholders_and_numbers() {
+        let args = [
+            ("name", StringArg::from("Ribbit")),
+            ("score", StringArg::from(1_234_567)),
+            ("time", StringArg::from(2.5)),
+        ];
+        assert_eq!(
+            format_string(
+                "{name}: {score} in {time:.2}s {unknown}",
+                &args,
+                Locale("en")
+            ),
+            "Ribbit: 1,234,567 in 2.50s {unknown}",
+            "English groups with commas"
+        );
+        assert_eq!(
+            format_string("{score} en {time:.1}s", &args, Locale("fr")),
+            "1\u{202f}234\u{202f}567 en 2,5s",
+            "French groups with narrow spaces and uses a decimal comma"
+        );
+        assert_eq!(
+            format_number("-1000", Locale("en")),
+            "-1,000",
+            "Signs are kept"
+        );
+    }
+
+    #[test]
+    fn keeps_unclosed_and_unknown_placeholders() {
+        let args = [("name", StringArg::from("Ribbit"))];
+        assert_eq!(format_string("a {b", &args, Locale("en")), "a {b");
+        assert_eq!(
+            format_string("{name} {name", &args, Locale("en")),
+            "Ribbit {name"
+        );
+        assert_eq!(
+            format_string("{other} and {name}", &args, Locale("en")),
+            "{other} and Ribbit"
+        );
+    }
+
+    #[test]
+    fn selects_plural_forms() {
+        let entry = StringEntry::Plural {
+            zero: Some("none".to_string()),
+            one: Some("one".to_string()),
+            other: "many".to_string(),
+        };
+        assert_eq!(
+            entry.select(Locale("en"), Some(0)),
+            "none",
+            "Zero wins when present"
+        );
+        assert_eq!(
+            entry.select(Locale("en"), Some(1)),
+            "one",
+            "English singular"
+        );
+        assert_eq!(
+            entry.select(Locale("en"), Some(2)),
+            "many",
+            "English plural"
+        );
+
+        let entry = StringEntry::Plural {
+            zero: None,
+            one: Some("one".to_string()),
+            other: "many".to_string(),
+        };
+        assert_eq!(
+            entry.select(Locale("fr"), Some(0)),
+            "one",
+            "French 0 is singular"
+        );
+        assert_eq!(
+            entry.select(Locale("en"), Some(0)),
+            "many",
+            "English 0 is plural"
+        );
+    }
+}
@@ -24,9 +24,7 @@ pub fn just_pressed_world_position(
 ) -> Option<Vec2> {
     let position = just_pressed_screen_position(button_input, touch_input, windows)?;
 
-    let (camera, camera_transform) = camera.single();
-
-    camera.viewport_to_world_2d(camera_transform, position).ok()
+    screen_to_world(camera, position)
 }
 
 pub fn just_released_screen_position(
@@ -56,9 +54,7 @@ pub fn just_released_world_position(
 ) -> Option<Vec2> {
     let position = just_released_screen_position(button_input, touch_input, windows)?;
 
-    let (camera, camera_transform) = camera.single();
-
-    camera.viewport_to_world_2d(camera_transform, position).ok()
+    screen_to_world(camera, position)
 }
 
 pub fn pressed_screen_position(
@@ -82,7 +78,36 @@ pub fn pressed_world_position(
 ) -> Option<Vec2> {
     let position = pressed_screen_position(button_input, touch_input, windows)?;
 
+    screen_to_world(camera, position)
+}
+
+/// Converts a window position to world coordinates, accounting for letterboxed viewports
+pub fn screen_to_world(
+    camera: &Query<(&Camera, &GlobalTransform)>,
+    position: Vec2,
+) -> Option<Vec2> {
+    let (camera, camera_transform) = camera.single();
+    let offset = camera
+        .logical_viewport_rect()
+        .map_or(Vec2::ZERO, |viewport| viewport.min);
+
+    camera
+        .viewport_to_world_2d(camera_transform, position - offset)
+        .ok()
+}
+
+/// Converts world coordinates to a window position, accounting for letterboxed viewports
+pub fn world_to_screen(
+    camera: &Query<(&Camera, &GlobalTransform)>,
+    position: Vec2,
+) -> Option<Vec2> {
     let (camera, camera_transform) = camera.single();
+    let offset = camera
+        .logical_viewport_rect()
+        .map_or(Vec2::ZERO, |viewport| viewport.min);
 
-    camera.viewport_to_world_2d(camera_transform, position).ok()
+    camera
+        .world_to_viewport(camera_transform, position.extend(0.0))
+        .ok()
+        .map(|viewport_position| viewport_position + offset)
 }
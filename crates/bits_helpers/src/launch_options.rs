@@ -0,0 +1,32 @@
+/// Reads an option the bit was launched with
+///
+/// On wasm, from the query string of the page, e.g. `?locale=fr&muted=1`.
+/// On native, from the matching `BITS_*` environment variable, e.g. `BITS_LOCALE`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn launch_option(name: &str) -> Option<String> {
+    std::env::var(format!("BITS_{}", name.to_uppercase())).ok()
+}
+
+/// Reads an option the bit was launched with
+///
+/// On wasm, from the query string of the page, e.g. `?locale=fr&muted=1`.
+/// On native, from the matching `BITS_*` environment variable, e.g. `BITS_LOCALE`.
+#[cfg(target_arch = "wasm32")]
+pub fn launch_option(name: &str) -> Option<String> {
+    web_sys::window()?
+        .location()
+        .search()
+        .ok()
+        .and_then(|search| web_sys::UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get(name))
+}
+
+/// Reads a boolean launch option, set by `1`, `true`, `yes` or `on`, or by an empty value
+pub fn launch_flag(name: &str) -> bool {
+    launch_option(name).is_some_and(|value| {
+        matches!(
+            value.trim().to_lowercase().as_str(),
+            "" | "1" | "true" | "yes" | "on"
+        )
+    })
+}
@@ -1,10 +1,27 @@
 mod bit;
 pub use bit::*;
 
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod auto_pause;
+pub mod debug;
+pub mod difficulty;
 pub mod emoji;
 pub mod floating_score;
+pub mod hud;
+pub mod i18n;
 pub mod input;
+pub mod launch_options;
+pub mod loading;
+pub mod play_clock;
 pub mod restart;
+pub mod rich_text;
+pub mod score;
+pub mod sfxr;
+pub mod shared_assets;
+pub mod storage;
+pub mod tuning;
+pub mod virtual_resolution;
 pub mod welcome_screen;
 
 mod ribbit_communication;
@@ -15,3 +32,7 @@ mod window_resizing;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod ribbit_simulation;
+#[cfg(not(target_arch = "wasm32"))]
+pub use ribbit_simulation::BIT_RESULT_PREFIX;
+#[cfg(not(target_arch = "wasm32"))]
+mod time_control;
@@ -0,0 +1,241 @@
+use bevy::asset::{AssetPath, RecursiveDependencyLoadState};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use ribbit_bits::BitMessage;
+
+use crate::emoji::AtlasValidation;
+use crate::i18n::Strings;
+use crate::{FONT, send_bit_message};
+
+const LOADING_FONT_SIZE: f32 = 24.0;
+const PROGRESS_SIZE: Vec2 = Vec2::new(200.0, 8.0);
+const PROGRESS_BACKGROUND: Color = Color::srgba(1.0, 1.0, 1.0, 0.2);
+const PROGRESS_COLOR: Color = Color::srgb(0.0, 1.0, 0.0);
+
+/// Holds a bit back until its assets are loaded, showing a progress screen meanwhile
+///
+/// Bits load the assets they need right away through [`Preload`], usually in a
+/// `Startup` system, and start their game on `OnEnter(LoadingState::Loaded)`:
+///
+/// ```ignore
+/// fn load_card_back(mut commands: Commands, mut preload: Preload) {
+///     commands.insert_resource(CardBackTexture(preload.load(CARD_BACK)));
+/// }
+///
+/// app.add_systems(Startup, load_card_back)
+///     .add_systems(OnEnter(LoadingState::Loaded), start_game);
+/// ```
+///
+/// The shared font, the emoji atlas when the `EmojiPlugin` is added, and the strings of
+/// the current locale are always waited for. The platform is told the bit is ready once
+/// everything is loaded.
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<LoadingState>()
+            .init_resource::<RequiredAssets>()
+            .add_systems(Startup, (load_shared_assets, spawn_loading_screen))
+            .add_systems(
+                Update,
+                update_loading.run_if(in_state(LoadingState::Loading)),
+            )
+            .add_systems(OnExit(LoadingState::Loading), despawn_loading_screen)
+            .add_systems(OnEnter(LoadingState::Loaded), ready);
+    }
+}
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadingState {
+    #[default]
+    Loading,
+    Loaded,
+}
+
+/// Assets the bit cannot start without
+#[derive(Resource, Default)]
+pub struct RequiredAssets(Vec<UntypedHandle>);
+
+impl RequiredAssets {
+    /// Waits for `handle`, which is kept alive for the rest of the run
+    pub fn require(&mut self, handle: impl Into<UntypedHandle>) {
+        self.0.push(handle.into());
+    }
+}
+
+/// System parameter loading assets and marking them as required
+#[derive(SystemParam)]
+pub struct Preload<'w> {
+    asset_server: Res<'w, AssetServer>,
+    required: ResMut<'w, RequiredAssets>,
+}
+
+impl Preload<'_> {
+    pub fn load<'a, A: Asset>(&mut self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
+        let handle = self.asset_server.load(path);
+        self.required.require(handle.clone());
+        handle
+    }
+}
+
+#[derive(Component)]
+struct LoadingScreen;
+
+#[derive(Component)]
+struct LoadingProgress;
+
+fn load_shared_assets(mut preload: Preload) {
+    preload.load::<Font>(FONT);
+}
+
+fn spawn_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>, strings: Strings) {
+    commands
+        .spawn((
+            LoadingScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(LOADING_FONT_SIZE),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+            GlobalZIndex(i32::MAX),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(strings.get("loading.title")),
+                TextFont {
+                    font: asset_server.load(FONT),
+                    font_size: LOADING_FONT_SIZE,
+                    ..default()
+                },
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(PROGRESS_SIZE.x),
+                        height: Val::Px(PROGRESS_SIZE.y),
+                        ..default()
+                    },
+                    BackgroundColor(PROGRESS_BACKGROUND),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        LoadingProgress,
+                        Node {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(PROGRESS_COLOR),
+                    ));
+                });
+        });
+}
+
+fn update_loading(
+    asset_server: Res<AssetServer>,
+    required: Res<RequiredAssets>,
+    atlas: Option<Res<AtlasValidation>>,
+    strings: Strings,
+    mut progress_bars: Query<&mut Node, With<LoadingProgress>>,
+    mut next_state: ResMut<NextState<LoadingState>>,
+) {
+    // Failed assets are logged by the asset server, the bit starts without them
+    let loaded = required
+        .0
+        .iter()
+        .filter(|handle| {
+            matches!(
+                asset_server.recursive_dependency_load_state(*handle),
+                RecursiveDependencyLoadState::Loaded | RecursiveDependencyLoadState::Failed(_)
+            )
+        })
+        .count();
+    // A missing or unusable atlas is logged, the bit starts without emojis
+    let atlas_ready = atlas.is_none_or(|atlas| atlas.is_loaded() || atlas.is_failed());
+
+    let steps = required.0.len() + 2;
+    let done = loaded + usize::from(atlas_ready) + usize::from(strings.is_ready());
+    for mut node in &mut progress_bars {
+        node.width = Val::Percent(100.0 * done as f32 / steps as f32);
+    }
+
+    if done == steps {
+        next_state.set(LoadingState::Loaded);
+    }
+}
+
+fn despawn_loading_screen(mut commands: Commands, screens: Query<Entity, With<LoadingScreen>>) {
+    for screen in &screens {
+        commands.entity(screen).despawn_recursive();
+    }
+}
+
+fn ready() {
+    send_bit_message(BitMessage::Ready);
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy::state::app::StatesPlugin;
+    use bevy::text::FontLoader;
+
+    use super::*;
+    use crate::emoji::EmojiPlugin;
+    use crate::i18n::I18nPlugin;
+    use crate::shared_assets::SharedAssetsPlugin;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            SharedAssetsPlugin,
+            AssetPlugin::default(),
+            StatesPlugin,
+            I18nPlugin,
+            LoadingPlugin,
+        ))
+        .init_asset::<Font>()
+        .init_asset_loader::<FontLoader>();
+        app
+    }
+
+    /// Updates the app until it is loaded, assets loading on other threads
+    fn wait_loaded(app: &mut App) -> bool {
+        (0..500).any(|_| {
+            std::thread::sleep(Duration::from_millis(10));
+            app.update();
+            *app.world().resource::<State<LoadingState>>() == LoadingState::Loaded
+        })
+    }
+
+    #[test]
+    fn loads_the_shared_font() {
+        let mut app = test_app();
+        assert!(wait_loaded(&mut app), "the shared font should load");
+    }
+
+    #[test]
+    fn loads_without_failed_assets() {
+        let mut app = test_app();
+        // No image loader is registered, so the emoji atlas fails to load
+        app.add_plugins(EmojiPlugin)
+            .init_asset::<Image>()
+            .init_asset::<TextureAtlasLayout>()
+            .add_systems(Startup, |mut preload: Preload| {
+                preload.load::<Font>("fonts/missing.ttf");
+            });
+
+        assert!(
+            wait_loaded(&mut app),
+            "failed assets should not hold the bit back"
+        );
+    }
+}
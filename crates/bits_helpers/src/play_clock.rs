@@ -0,0 +1,181 @@
+use core::time::Duration;
+
+use bevy::prelude::*;
+use ribbit_bits::BitResult;
+
+/// Measures pure play time, for bits whose result is a duration
+///
+/// The clock only advances while the bit is in one of its playing states, and follows
+/// [`Time<Virtual>`], so it stops with the simulation's pause and time scale:
+///
+/// ```ignore
+/// app.add_plugins(PlayClockPlugin::new().in_state(GameState::Playing));
+///
+/// fn on_finish(clock: Res<PlayClock>) {
+///     send_bit_message(BitMessage::End(clock.fastest_result()));
+/// }
+/// ```
+///
+/// It is reset when Ribbit restarts the bit. When a bit uses it, the simulation's timer
+/// bar only runs down during play as well.
+#[derive(Default)]
+pub struct PlayClockPlugin {
+    states: Vec<Box<dyn Fn(&mut App) + Send + Sync>>,
+}
+
+impl PlayClockPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the clock in `state`, several states adding up
+    #[must_use]
+    pub fn in_state<S: States>(mut self, state: S) -> Self {
+        self.states.push(Box::new(move |app| {
+            app.add_systems(OnEnter(state.clone()), |mut clock: ResMut<PlayClock>| {
+                clock.playing_states += 1;
+            })
+            .add_systems(OnExit(state.clone()), |mut clock: ResMut<PlayClock>| {
+                clock.playing_states = clock.playing_states.saturating_sub(1);
+            });
+        }));
+        self
+    }
+}
+
+impl Plugin for PlayClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayClock>()
+            .add_systems(PreUpdate, tick_play_clock.in_set(PlayClockSystem));
+
+        for state in &self.states {
+            state(app);
+        }
+    }
+}
+
+/// Advances the [`PlayClock`], systems reading its delta run after it
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlayClockSystem;
+
+#[derive(Resource, Default)]
+pub struct PlayClock {
+    elapsed: Duration,
+    delta: Duration,
+    playing_states: u32,
+    paused: bool,
+}
+
+impl PlayClock {
+    /// Play time since the last reset
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Play time added this frame, zero outside of play
+    pub const fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Whether the clock advances, the bit being in a playing state and not paused
+    pub const fn is_running(&self) -> bool {
+        self.playing_states > 0 && !self.paused
+    }
+
+    /// Stops the clock while still playing, e.g. during a stage transition
+    pub const fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub const fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Starts over from zero, keeping the current state
+    pub const fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.delta = Duration::ZERO;
+        self.paused = false;
+    }
+
+    /// Result for bits won by finishing quickly
+    pub const fn fastest_result(&self) -> BitResult {
+        BitResult::FastestDuration(self.elapsed)
+    }
+
+    /// Result for bits won by lasting long
+    pub const fn longest_result(&self) -> BitResult {
+        BitResult::LongestDuration(self.elapsed)
+    }
+
+    fn tick(&mut self, delta: Duration) {
+        self.delta = if self.is_running() {
+            delta
+        } else {
+            Duration::ZERO
+        };
+        self.elapsed += self.delta;
+    }
+}
+
+fn tick_play_clock(mut clock: ResMut<PlayClock>, time: Res<Time<Virtual>>) {
+    clock.tick(time.delta());
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum GameState {
+        #[default]
+        Welcome,
+        Playing,
+    }
+
+    #[test]
+    fn only_runs_while_playing() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .add_plugins(PlayClockPlugin::new().in_state(GameState::Playing));
+        app.update();
+
+        let step = Duration::from_millis(100);
+        let advance = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time<Virtual>>()
+                .advance_by(step);
+            app.world_mut()
+                .run_system_once(tick_play_clock)
+                .expect("The clock ticks");
+        };
+
+        advance(&mut app);
+        assert_eq!(
+            app.world().resource::<PlayClock>().elapsed(),
+            Duration::ZERO
+        );
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.world_mut().resource_mut::<PlayClock>().reset();
+        advance(&mut app);
+        advance(&mut app);
+        assert_eq!(app.world().resource::<PlayClock>().elapsed(), step * 2);
+
+        app.world_mut().resource_mut::<PlayClock>().pause();
+        advance(&mut app);
+        let clock = app.world().resource::<PlayClock>();
+        assert_eq!(clock.delta(), Duration::ZERO);
+        assert!(matches!(
+            clock.longest_result(),
+            BitResult::LongestDuration(duration) if duration == step * 2
+        ));
+    }
+}
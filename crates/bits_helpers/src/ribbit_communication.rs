@@ -8,6 +8,9 @@ use wasm_bindgen::prelude::*;
 #[cfg(target_arch = "wasm32")]
 use web_sys::MessageEvent;
 
+use crate::play_clock::PlayClock;
+use crate::score::ScoreKeeper;
+
 pub static RIBBIT_MESSAGE_QUEUE: LazyLock<Arc<Mutex<Vec<RibbitMessage>>>> =
     LazyLock::new(|| Arc::new(Mutex::new(Vec::new())));
 
@@ -84,7 +87,15 @@ fn proccess_ribbit_messages<T: RibbitMessageHandler>(world: &mut World) {
                 let parameters = BitParameters { duration };
                 send_bit_message(BitMessage::Parameters(parameters));
             }
-            RibbitMessage::Restart => T::restart(world),
+            RibbitMessage::Restart => {
+                if let Some(mut clock) = world.get_resource_mut::<PlayClock>() {
+                    clock.reset();
+                }
+                if let Some(mut keeper) = world.get_resource_mut::<ScoreKeeper>() {
+                    keeper.reset();
+                }
+                T::restart(world);
+            }
             RibbitMessage::Start => {
                 // Todo : Block in PostStartup until we receive this message.
             }
@@ -92,10 +103,6 @@ fn proccess_ribbit_messages<T: RibbitMessageHandler>(world: &mut World) {
     }
 }
 
-fn ready() {
-    send_bit_message(BitMessage::Ready);
-}
-
 #[derive(Default)]
 pub struct RibbitCommunicationPlugin<T: RibbitMessageHandler>(core::marker::PhantomData<T>);
 
@@ -106,6 +113,5 @@ impl<T: RibbitMessageHandler> Plugin for RibbitCommunicationPlugin<T> {
         {
             app.add_systems(Startup, listen_ribbit_messages);
         }
-        app.add_systems(PostStartup, ready);
     }
 }
@@ -3,8 +3,16 @@ use std::time::Duration;
 use bevy::prelude::*;
 use ribbit_bits::{BitDuration, BitMessage, BitResult, RibbitMessage};
 
+use crate::i18n::Strings;
+use crate::launch_options::launch_flag;
+use crate::play_clock::PlayClock;
+use crate::score::ScoreKeeper;
+use crate::time_control::TimeControlPlugin;
 use crate::{BIT_MESSAGE_QUEUE, FONT, RIBBIT_MESSAGE_QUEUE, WINDOW_HEIGHT, WINDOW_WIDTH};
 
+/// Starts the line on which a bit launched with the `exit_on_end` option prints its result
+pub const BIT_RESULT_PREFIX: &str = "bit_result: ";
+
 /// Event sent when the game over screen should be cleaned up
 #[derive(Event)]
 pub struct CleanupGameOverEvent;
@@ -14,6 +22,7 @@ pub struct RibbitSimulation;
 impl Plugin for RibbitSimulation {
     fn build(&self, app: &mut App) {
         app.add_event::<CleanupGameOverEvent>();
+        app.add_plugins(TimeControlPlugin);
         app.add_systems(Startup, ribbit_simulation_init);
         app.add_systems(Update, ribbit_simulation);
         app.add_systems(Update, update_timer_display);
@@ -49,10 +58,13 @@ fn ribbit_simulation_init(mut commands: Commands) {
 fn update_timer_display(
     mut progress_bar_query: Query<&mut Transform, With<TimerProgressBar>>,
     mut game_timer: ResMut<GameTimer>,
-    time: Res<Time>,
+    time: Res<Time<Virtual>>,
+    play_clock: Option<Res<PlayClock>>,
 ) {
-    // Tick the game timer
-    game_timer.timer.tick(time.delta());
+    // Tick the game timer with the bit's time, so it follows the time scale and pauses,
+    // and only during play when the bit measures it
+    let delta = play_clock.map_or_else(|| time.delta(), |clock| clock.delta());
+    game_timer.timer.tick(delta);
 
     // Update the progress bar width based on remaining time
     if let Some(mut transform) = progress_bar_query.iter_mut().next() {
@@ -74,7 +86,10 @@ fn ribbit_simulation(
     keycode: Res<ButtonInput<KeyCode>>,
     mut game_timer: ResMut<GameTimer>,
     asset_server: Res<AssetServer>,
+    strings: Strings,
+    score_keeper: Option<Res<ScoreKeeper>>,
     mut event_writer: EventWriter<CleanupGameOverEvent>,
+    mut exit: EventWriter<AppExit>,
 ) {
     if keycode.just_pressed(KeyCode::KeyR) {
         RIBBIT_MESSAGE_QUEUE.lock().push(RibbitMessage::Restart);
@@ -114,18 +129,32 @@ fn ribbit_simulation(
             BitMessage::Start => {
                 info!("Start");
             }
+            BitMessage::End(bit_result) if launch_flag("exit_on_end") => {
+                // Launched by a playlist, which reads the result and moves on to the next bit
+                println!("{BIT_RESULT_PREFIX}{bit_result:?}");
+                exit.send(AppExit::Success);
+            }
             BitMessage::End(bit_result) => {
-                spawn_game_over_screen(&mut commands, &asset_server, bit_result);
+                spawn_game_over_screen(
+                    &mut commands,
+                    &asset_server,
+                    &strings,
+                    bit_result,
+                    score_keeper.as_deref(),
+                );
             }
         }
     }
 }
 
-/// Spawns the game over screen with final score
+/// Spawns the game over screen with final score, and the stars earned and rank reached
+/// when the bit keeps its score with a [`ScoreKeeper`]
 pub fn spawn_game_over_screen(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
+    strings: &Strings,
     bit_result: BitResult,
+    score_keeper: Option<&ScoreKeeper>,
 ) {
     // Create a semi-transparent overlay
     commands.spawn((
@@ -142,7 +171,7 @@ pub fn spawn_game_over_screen(
     // Game Over text
     commands.spawn((
         GameOverScreen,
-        Text::new("Game Over!"),
+        Text::new(strings.get("game_over.title")),
         TextFont {
             font: asset_server.load(FONT),
             font_size: 48.0,
@@ -161,13 +190,13 @@ pub fn spawn_game_over_screen(
     // Final score
     let text = match bit_result {
         BitResult::LowestScore(score) | BitResult::HighestScore(score) => {
-            format!("Final score: {score}")
+            strings.format("game_over.final_score", &[("score", score.into())])
         }
         BitResult::LongestDuration(duration) | BitResult::FastestDuration(duration) => {
-            format!("Time: {:.2}", duration.as_secs_f32())
+            strings.format("game_over.time", &[("time", duration.as_secs_f32().into())])
         }
-        BitResult::Success => "You won!".to_string(),
-        BitResult::Failure => "You lost! Try again!".to_string(),
+        BitResult::Success => strings.get("game_over.won"),
+        BitResult::Failure => strings.get("game_over.lost"),
     };
 
     commands.spawn((
@@ -188,9 +217,57 @@ pub fn spawn_game_over_screen(
         },
     ));
 
+    if let Some(keeper) = score_keeper.filter(|keeper| keeper.max_stars() > 0) {
+        let stars = strings.format(
+            "game_over.stars",
+            &[
+                ("stars", keeper.stars().into()),
+                ("max", keeper.max_stars().into()),
+            ],
+        );
+        commands.spawn((
+            GameOverScreen,
+            Text::new(stars),
+            TextFont {
+                font: asset_server.load(FONT),
+                font_size: 32.0,
+                ..default()
+            },
+            TextLayout::new_with_justify(JustifyText::Center),
+            TextColor(Color::BLACK),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(55.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+        ));
+    }
+
+    if let Some(rank) = score_keeper.and_then(ScoreKeeper::rank) {
+        let rank = strings.format("game_over.rank", &[("rank", strings.get(rank).into())]);
+        commands.spawn((
+            GameOverScreen,
+            Text::new(rank),
+            TextFont {
+                font: asset_server.load(FONT),
+                font_size: 32.0,
+                ..default()
+            },
+            TextLayout::new_with_justify(JustifyText::Center),
+            TextColor(Color::BLACK),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(62.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+        ));
+    }
+
     commands.spawn((
         GameOverScreen,
-        Text::new("Press 'R' to restart"),
+        Text::new(strings.get("game_over.restart")),
         TextFont {
             font: asset_server.load(FONT),
             font_size: 32.0,
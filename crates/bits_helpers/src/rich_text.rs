@@ -0,0 +1,364 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::emoji::{EmojiPlugin, Emojis};
+use crate::loading::Preload;
+
+pub const MANIFEST_PATH: &str = "bits://emojis/EmojiAtlas.manifest.ron";
+
+/// Adds support for text mixing words and emojis, e.g. `"Collect :apple: x3!"`
+///
+/// Emojis can be written as `:shortcode:`, as raw codepoints, or as `:#<atlas index>:`.
+/// Shortcodes and codepoints are resolved through the atlas manifest, which is
+/// generated next to the atlas texture.
+pub struct RichTextPlugin;
+
+impl Plugin for RichTextPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EmojiPlugin>() {
+            app.add_plugins(EmojiPlugin);
+        }
+
+        app.init_asset::<EmojiManifest>()
+            .init_asset_loader::<EmojiManifestLoader>()
+            .add_systems(Startup, load_emoji_manifest);
+    }
+}
+
+/// Lookup tables from shortcodes and codepoint sequences to atlas indices
+#[derive(Asset, TypePath, Debug, Default)]
+pub struct EmojiManifest {
+    by_shortcode: HashMap<String, usize>,
+    by_sequence: HashMap<String, usize>,
+    /// Length in chars of the longest sequence, bounds the codepoint matching
+    longest_sequence: usize,
+}
+
+impl EmojiManifest {
+    /// Gets the atlas index of a shortcode, without the surrounding colons
+    pub fn shortcode(&self, shortcode: &str) -> Option<usize> {
+        self.by_shortcode.get(shortcode).copied()
+    }
+
+    /// Gets the atlas index of an emoji written as codepoints
+    pub fn sequence(&self, sequence: &str) -> Option<usize> {
+        self.by_sequence.get(sequence).copied()
+    }
+
+    /// Matches the longest emoji sequence at the start of `text`,
+    /// returning its atlas index and its length in bytes
+    fn match_prefix(&self, text: &str) -> Option<(usize, usize)> {
+        text.char_indices()
+            .skip(1)
+            .map(|(i, _)| i)
+            .chain(core::iter::once(text.len()))
+            .take(self.longest_sequence)
+            .filter_map(|end| Some((self.sequence(text.get(..end)?)?, end)))
+            .last()
+    }
+}
+
+#[derive(Deserialize)]
+struct ManifestFile {
+    emojis: Vec<ManifestEntry>,
+}
+
+#[derive(Deserialize)]
+struct ManifestEntry {
+    index: usize,
+    sequence: String,
+    #[serde(default)]
+    shortcodes: Vec<String>,
+}
+
+impl From<ManifestFile> for EmojiManifest {
+    fn from(file: ManifestFile) -> Self {
+        let mut manifest = Self::default();
+        for entry in file.emojis {
+            manifest.longest_sequence = manifest
+                .longest_sequence
+                .max(entry.sequence.chars().count());
+            manifest.by_sequence.insert(entry.sequence, entry.index);
+            for shortcode in entry.shortcodes {
+                manifest.by_shortcode.insert(shortcode, entry.index);
+            }
+        }
+        manifest
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum EmojiManifestLoaderError {
+    #[error("Could not read emoji manifest: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not parse emoji manifest: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[derive(Default)]
+struct EmojiManifestLoader;
+
+impl AssetLoader for EmojiManifestLoader {
+    type Asset = EmojiManifest;
+    type Settings = ();
+    type Error = EmojiManifestLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file: ManifestFile = ron::de::from_bytes(&bytes)?;
+        Ok(file.into())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["manifest.ron"]
+    }
+}
+
+#[derive(Resource)]
+struct EmojiManifestHandle(Handle<EmojiManifest>);
+
+fn load_emoji_manifest(mut commands: Commands, mut preload: Preload) {
+    commands.insert_resource(EmojiManifestHandle(preload.load(MANIFEST_PATH)));
+}
+
+/// A run of rich text, either plain text or an emoji from the atlas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RichSegment {
+    Text(String),
+    Emoji(usize),
+}
+
+/// Splits `text` into text runs and emojis
+///
+/// Without a manifest only `:#<atlas index>:` emojis are recognized.
+/// Anything that does not resolve to an emoji is kept as text.
+pub fn parse_rich_text(text: &str, manifest: Option<&EmojiManifest>) -> Vec<RichSegment> {
+    let mut segments = Vec::new();
+    let mut run = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let emoji = if c == ':' {
+            parse_shortcode(rest, manifest)
+        } else {
+            manifest.and_then(|manifest| manifest.match_prefix(rest))
+        };
+
+        let consumed = if let Some((index, consumed)) = emoji {
+            if !run.is_empty() {
+                segments.push(RichSegment::Text(core::mem::take(&mut run)));
+            }
+            segments.push(RichSegment::Emoji(index));
+            consumed
+        } else {
+            run.push(c);
+            c.len_utf8()
+        };
+        rest = rest.get(consumed..).unwrap_or_default();
+    }
+
+    if !run.is_empty() {
+        segments.push(RichSegment::Text(run));
+    }
+
+    segments
+}
+
+/// Parses a `:shortcode:` or `:#index:` at the start of `text`,
+/// returning the atlas index and the length in bytes including both colons
+fn parse_shortcode(text: &str, manifest: Option<&EmojiManifest>) -> Option<(usize, usize)> {
+    let body = text.strip_prefix(':')?;
+    let end = body.find(':')?;
+    let name = body.get(..end)?;
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let index = match name.strip_prefix('#') {
+        Some(index) => index.parse().ok()?,
+        None => manifest?.shortcode(name)?,
+    };
+
+    Some((index, end + 2))
+}
+
+/// System parameter to spawn UI labels mixing text and emojis
+#[derive(SystemParam)]
+pub struct RichText<'w> {
+    emojis: Emojis<'w>,
+    manifest: Res<'w, EmojiManifestHandle>,
+    manifests: Res<'w, Assets<EmojiManifest>>,
+}
+
+impl RichText<'_> {
+    /// Returns whether emojis can be displayed and the manifest is loaded
+    pub fn is_ready(&self) -> bool {
+        self.emojis.is_ready() && self.manifests.contains(&self.manifest.0)
+    }
+
+    /// Splits `text` into text runs and emojis using the loaded manifest
+    pub fn parse(&self, text: &str) -> Vec<RichSegment> {
+        parse_rich_text(text, self.manifests.get(&self.manifest.0))
+    }
+
+    /// Spawns a row laying out `text` with emojis inline, sized to `font`
+    ///
+    /// `node` positions the row; its flex direction is forced to a row.
+    pub fn spawn(
+        &self,
+        commands: &mut Commands,
+        text: &str,
+        node: Node,
+        font: &TextFont,
+        color: TextColor,
+    ) -> Entity {
+        let segments = self.parse(text);
+        commands
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                ..node
+            })
+            .with_children(|parent| self.spawn_segments(parent, &segments, font, color))
+            .id()
+    }
+
+    /// Spawns the text runs and emojis of `segments` as children of a row node
+    pub fn spawn_segments(
+        &self,
+        parent: &mut ChildBuilder,
+        segments: &[RichSegment],
+        font: &TextFont,
+        color: TextColor,
+    ) {
+        for segment in segments {
+            match segment {
+                RichSegment::Text(text) => {
+                    parent.spawn((Text::new(text.as_str()), font.clone(), color));
+                }
+                RichSegment::Emoji(index) => match self.emojis.ui_span(*index, font) {
+                    Ok(span) => {
+                        parent.spawn(span);
+                    }
+                    Err(err) => warn!("Could not display emoji in rich text: {err}"),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emoji::{ATLAS_SIZE, EMOJI_SIZE};
+
+    fn manifest() -> EmojiManifest {
+        let file: ManifestFile = ron::de::from_str(
+            r#"(
+                emojis: [
+                    (index: 1, sequence: "🍎", shortcodes: ["apple"]),
+                    (index: 2, sequence: "👨", shortcodes: ["man"]),
+                    (index: 3, sequence: "👨‍👩‍👧", shortcodes: ["family"]),
+                ],
+            )"#,
+        )
+        .expect("The manifest parses");
+        file.into()
+    }
+
+    fn text(text: &str) -> RichSegment {
+        RichSegment::Text(text.to_owned())
+    }
+
+    #[test]
+    fn parses_the_shipped_manifest() {
+        let file: ManifestFile =
+            ron::de::from_str(include_str!("../assets/emojis/EmojiAtlas.manifest.ron"))
+                .expect("The shipped manifest parses");
+        let cells = (ATLAS_SIZE / EMOJI_SIZE).element_product() as usize;
+        assert!(file.emojis.iter().all(|entry| entry.index < cells));
+        let manifest = EmojiManifest::from(file);
+
+        let apple = manifest
+            .shortcode("apple")
+            .expect(":apple: is in the manifest");
+        assert_eq!(manifest.shortcode("red_apple"), Some(apple));
+        assert_eq!(
+            parse_rich_text("Collect :apple: 🍎", Some(&manifest)),
+            [
+                text("Collect "),
+                RichSegment::Emoji(apple),
+                text(" "),
+                RichSegment::Emoji(apple)
+            ]
+        );
+        assert_eq!(manifest.shortcode("flag_fr"), manifest.sequence("🇫🇷"));
+        assert!(manifest.sequence("🇫🇷").is_some());
+    }
+
+    #[test]
+    fn keeps_plain_text_in_one_run() {
+        assert_eq!(
+            parse_rich_text("Hello, world!", Some(&manifest())),
+            [text("Hello, world!")]
+        );
+        assert!(parse_rich_text("", None).is_empty());
+    }
+
+    #[test]
+    fn parses_shortcodes_and_indices() {
+        assert_eq!(
+            parse_rich_text("Collect :apple: x3!", Some(&manifest())),
+            [text("Collect "), RichSegment::Emoji(1), text(" x3!")]
+        );
+        // Indices don't need a manifest
+        assert_eq!(
+            parse_rich_text(":#42::#7:", None),
+            [RichSegment::Emoji(42), RichSegment::Emoji(7)]
+        );
+        assert_eq!(parse_rich_text(":#abc:", None), [text(":#abc:")]);
+    }
+
+    #[test]
+    fn keeps_unknown_codes_and_lone_colons() {
+        let manifest = manifest();
+        assert_eq!(
+            parse_rich_text(":pear: :apple", Some(&manifest)),
+            [text(":pear: :apple")]
+        );
+        assert_eq!(
+            parse_rich_text("Time: 10:30 :apple:", Some(&manifest)),
+            [text("Time: 10:30 "), RichSegment::Emoji(1)]
+        );
+        assert_eq!(parse_rich_text(":", Some(&manifest)), [text(":")]);
+        assert_eq!(parse_rich_text("::", Some(&manifest)), [text("::")]);
+        assert_eq!(parse_rich_text(":apple:", None), [text(":apple:")]);
+    }
+
+    #[test]
+    fn matches_the_longest_sequence() {
+        let manifest = manifest();
+        assert_eq!(
+            parse_rich_text("👨‍👩‍👧👨!", Some(&manifest)),
+            [RichSegment::Emoji(3), RichSegment::Emoji(2), text("!")]
+        );
+        // A partial sequence falls back to its longest known prefix
+        assert_eq!(
+            parse_rich_text("👨‍👩", Some(&manifest)),
+            [RichSegment::Emoji(2), text("\u{200d}👩")]
+        );
+        assert_eq!(parse_shortcode(":family: ", Some(&manifest)), Some((3, 8)));
+    }
+}
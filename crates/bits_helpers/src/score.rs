@@ -0,0 +1,259 @@
+use bevy::prelude::*;
+use ribbit_bits::BitResult;
+
+use crate::hud::HudScore;
+
+/// Keeps the score of a bit and maps it to its [`BitResult`]
+///
+/// Bits record what happened, and read the result from it when the game ends or Ribbit
+/// asks for it:
+///
+/// ```ignore
+/// app.insert_resource(ScoreKeeper::new(ResultPolicy::HighestScore).with_stars([10, 25, 50]));
+///
+/// keeper.record(ScoreEvent::Combo(5));
+/// send_bit_message(BitMessage::End(keeper.result()));
+/// ```
+///
+/// It is reset when Ribbit restarts the bit, and the simulation's game over screen shows
+/// the stars earned and the rank reached.
+#[derive(Resource, Debug, Clone)]
+pub struct ScoreKeeper {
+    policy: ResultPolicy,
+    /// Scores to reach for each star, from the first star to the last
+    stars: Vec<u32>,
+    /// Keys of the rank names in the strings, with the score to reach for each, from the
+    /// lowest rank to the highest
+    ranks: Vec<(&'static str, u32)>,
+    score: u32,
+    bonus: u32,
+    combo: u32,
+    best_combo: u32,
+}
+
+/// What happened in the game, worth points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreEvent {
+    /// Points for an action that doesn't take part in combos
+    Points(u32),
+    /// Points for an action extending the combo by one
+    Combo(u32),
+    /// Points lost for a mistake, breaking the combo
+    Penalty(u32),
+    /// Extra points, e.g. for the time left, counted apart from the points scored
+    Bonus(u32),
+}
+
+/// How the score maps to the [`BitResult`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultPolicy {
+    #[default]
+    HighestScore,
+    LowestScore,
+    /// [`BitResult::Success`] once the score reaches the target, [`BitResult::Failure`]
+    /// otherwise
+    Target(u32),
+}
+
+impl ScoreKeeper {
+    pub const fn new(policy: ResultPolicy) -> Self {
+        Self {
+            policy,
+            stars: Vec::new(),
+            ranks: Vec::new(),
+            score: 0,
+            bonus: 0,
+            combo: 0,
+            best_combo: 0,
+        }
+    }
+
+    /// Rates the score with stars, each threshold being the score to reach for one more
+    /// star, or the score not to exceed with [`ResultPolicy::LowestScore`]
+    #[must_use]
+    pub fn with_stars(mut self, thresholds: impl Into<Vec<u32>>) -> Self {
+        self.stars = thresholds.into();
+        self
+    }
+
+    /// Names the score with ranks, each being the key of its name in the strings and the
+    /// score to reach for it, from the lowest rank to the highest:
+    ///
+    /// ```ignore
+    /// ScoreKeeper::new(ResultPolicy::HighestScore)
+    ///     .with_ranks([("rank.bronze", 10), ("rank.silver", 25), ("rank.gold", 50)])
+    /// ```
+    #[must_use]
+    pub fn with_ranks(mut self, ranks: impl Into<Vec<(&'static str, u32)>>) -> Self {
+        self.ranks = ranks.into();
+        self
+    }
+
+    pub const fn record(&mut self, event: ScoreEvent) {
+        match event {
+            ScoreEvent::Points(points) => {
+                self.score = self.score.saturating_add(points);
+            }
+            ScoreEvent::Combo(points) => {
+                self.score = self.score.saturating_add(points);
+                self.combo += 1;
+                if self.combo > self.best_combo {
+                    self.best_combo = self.combo;
+                }
+            }
+            ScoreEvent::Penalty(points) => {
+                self.score = self.score.saturating_sub(points);
+                self.combo = 0;
+            }
+            ScoreEvent::Bonus(points) => {
+                self.score = self.score.saturating_add(points);
+                self.bonus = self.bonus.saturating_add(points);
+            }
+        }
+    }
+
+    /// Score, bonus included
+    pub const fn total(&self) -> u32 {
+        self.score
+    }
+
+    /// Part of the score earned as bonus
+    pub const fn bonus(&self) -> u32 {
+        self.bonus
+    }
+
+    pub const fn combo(&self) -> u32 {
+        self.combo
+    }
+
+    pub const fn best_combo(&self) -> u32 {
+        self.best_combo
+    }
+
+    /// Overrides the score, e.g. from a debug command
+    pub const fn set_score(&mut self, score: u32) {
+        self.score = score;
+    }
+
+    /// Starts over from zero, keeping the policy, stars and ranks
+    pub const fn reset(&mut self) {
+        self.score = 0;
+        self.bonus = 0;
+        self.combo = 0;
+        self.best_combo = 0;
+    }
+
+    /// Number of stars earned
+    pub fn stars(&self) -> usize {
+        self.stars
+            .iter()
+            .filter(|&&threshold| self.reaches(threshold))
+            .count()
+    }
+
+    pub fn max_stars(&self) -> usize {
+        self.stars.len()
+    }
+
+    /// Key of the highest rank reached, `None` below the lowest one
+    pub fn rank(&self) -> Option<&'static str> {
+        self.ranks
+            .iter()
+            .rev()
+            .find(|&&(_, threshold)| self.reaches(threshold))
+            .map(|&(key, _)| key)
+    }
+
+    /// Whether the score reaches a star or rank threshold, or doesn't exceed it with
+    /// [`ResultPolicy::LowestScore`]
+    const fn reaches(&self, threshold: u32) -> bool {
+        match self.policy {
+            ResultPolicy::LowestScore => self.score <= threshold,
+            ResultPolicy::HighestScore | ResultPolicy::Target(_) => self.score >= threshold,
+        }
+    }
+
+    pub fn result(&self) -> BitResult {
+        match self.policy {
+            ResultPolicy::HighestScore => BitResult::HighestScore(self.score.into()),
+            ResultPolicy::LowestScore => BitResult::LowestScore(self.score.into()),
+            ResultPolicy::Target(target) if self.score >= target => BitResult::Success,
+            ResultPolicy::Target(_) => BitResult::Failure,
+        }
+    }
+}
+
+impl Default for ScoreKeeper {
+    fn default() -> Self {
+        Self::new(ResultPolicy::default())
+    }
+}
+
+impl HudScore for ScoreKeeper {
+    fn score(&self) -> u32 {
+        self.score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_events() {
+        let mut keeper = ScoreKeeper::new(ResultPolicy::HighestScore).with_stars([10, 20, 40]);
+        keeper.record(ScoreEvent::Combo(5));
+        keeper.record(ScoreEvent::Combo(5));
+        keeper.record(ScoreEvent::Penalty(3));
+        keeper.record(ScoreEvent::Points(4));
+        keeper.record(ScoreEvent::Bonus(10));
+
+        assert_eq!(keeper.total(), 21);
+        assert_eq!(keeper.bonus(), 10);
+        assert_eq!(keeper.combo(), 0);
+        assert_eq!(keeper.best_combo(), 2);
+        assert_eq!(keeper.stars(), 2);
+        assert!(matches!(keeper.result(), BitResult::HighestScore(21)));
+
+        keeper.record(ScoreEvent::Penalty(100));
+        assert_eq!(keeper.total(), 0);
+    }
+
+    #[test]
+    fn maps_policies() {
+        let mut keeper = ScoreKeeper::new(ResultPolicy::Target(10));
+        keeper.record(ScoreEvent::Points(9));
+        assert!(matches!(keeper.result(), BitResult::Failure));
+        keeper.record(ScoreEvent::Points(1));
+        assert!(matches!(keeper.result(), BitResult::Success));
+
+        let mut keeper = ScoreKeeper::new(ResultPolicy::LowestScore).with_stars([30, 20, 10]);
+        keeper.record(ScoreEvent::Points(15));
+        assert_eq!(keeper.stars(), 2);
+        assert!(matches!(keeper.result(), BitResult::LowestScore(15)));
+    }
+
+    #[test]
+    fn names_ranks() {
+        let mut keeper = ScoreKeeper::new(ResultPolicy::HighestScore).with_ranks([
+            ("rank.bronze", 10),
+            ("rank.silver", 25),
+            ("rank.gold", 50),
+        ]);
+        assert_eq!(keeper.rank(), None);
+        keeper.record(ScoreEvent::Points(30));
+        assert_eq!(keeper.rank(), Some("rank.silver"));
+        keeper.record(ScoreEvent::Points(20));
+        assert_eq!(keeper.rank(), Some("rank.gold"));
+        keeper.reset();
+        assert_eq!(keeper.rank(), None);
+
+        let mut keeper = ScoreKeeper::new(ResultPolicy::LowestScore).with_ranks([
+            ("rank.bronze", 30),
+            ("rank.silver", 20),
+            ("rank.gold", 10),
+        ]);
+        keeper.record(ScoreEvent::Points(15));
+        assert_eq!(keeper.rank(), Some("rank.silver"));
+    }
+}
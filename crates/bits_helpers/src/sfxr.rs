@@ -0,0 +1,702 @@
+use core::f32::consts::TAU;
+
+use fastrand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Sample rate of synthesized sounds, in Hz
+pub const SAMPLE_RATE: u32 = 44_100;
+
+/// Sounds stop after this many samples even if their envelope is not over
+const MAX_SAMPLES: usize = SAMPLE_RATE as usize * 5;
+const SUPERSAMPLES: usize = 8;
+const PHASER_SIZE: usize = 1024;
+const NOISE_SIZE: usize = 32;
+const MASTER_VOLUME: f32 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Waveform {
+    #[default]
+    Square,
+    Sawtooth,
+    Sine,
+    Noise,
+}
+
+/// Classic sfxr starting points, randomized from a seed
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::EnumIter, strum::EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum SfxrPreset {
+    Coin,
+    Hit,
+    Jump,
+    Blip,
+    Powerup,
+    Explosion,
+}
+
+impl SfxrPreset {
+    /// Generates the preset's parameters, the same seed always giving the same sound
+    pub fn generate(self, seed: u64) -> SfxrParams {
+        let mut rng = Rng::with_seed(seed);
+        let mut frnd = |range: f32| rng.f32() * range;
+        let mut params = SfxrParams::default();
+
+        match self {
+            Self::Coin => {
+                params.base_freq = 0.4 + frnd(0.5);
+                params.env_attack = 0.0;
+                params.env_sustain = frnd(0.1);
+                params.env_decay = 0.1 + frnd(0.4);
+                params.env_punch = 0.3 + frnd(0.3);
+                if frnd(1.0) < 0.5 {
+                    params.arp_speed = 0.5 + frnd(0.2);
+                    params.arp_mod = 0.2 + frnd(0.4);
+                }
+            }
+            Self::Hit => {
+                params.waveform = match frnd(3.0) as usize {
+                    0 => Waveform::Square,
+                    1 => Waveform::Sawtooth,
+                    _ => Waveform::Noise,
+                };
+                if params.waveform == Waveform::Square {
+                    params.duty = frnd(0.6);
+                }
+                params.base_freq = 0.2 + frnd(0.6);
+                params.freq_ramp = -0.3 - frnd(0.4);
+                params.env_attack = 0.0;
+                params.env_sustain = frnd(0.1);
+                params.env_decay = 0.1 + frnd(0.2);
+                if frnd(1.0) < 0.5 {
+                    params.hpf_freq = frnd(0.3);
+                }
+            }
+            Self::Jump => {
+                params.waveform = Waveform::Square;
+                params.duty = frnd(0.6);
+                params.base_freq = 0.3 + frnd(0.3);
+                params.freq_ramp = 0.1 + frnd(0.2);
+                params.env_attack = 0.0;
+                params.env_sustain = 0.1 + frnd(0.3);
+                params.env_decay = 0.1 + frnd(0.2);
+                if frnd(1.0) < 0.5 {
+                    params.hpf_freq = frnd(0.3);
+                }
+                if frnd(1.0) < 0.5 {
+                    params.lpf_freq = 1.0 - frnd(0.6);
+                }
+            }
+            Self::Blip => {
+                if frnd(1.0) < 0.5 {
+                    params.waveform = Waveform::Square;
+                    params.duty = frnd(0.6);
+                } else {
+                    params.waveform = Waveform::Sawtooth;
+                }
+                params.base_freq = 0.2 + frnd(0.4);
+                params.env_attack = 0.0;
+                params.env_sustain = 0.1 + frnd(0.1);
+                params.env_decay = frnd(0.2);
+                params.hpf_freq = 0.1;
+            }
+            Self::Powerup => {
+                if frnd(1.0) < 0.5 {
+                    params.waveform = Waveform::Sawtooth;
+                } else {
+                    params.duty = frnd(0.6);
+                }
+                params.base_freq = 0.2 + frnd(0.3);
+                if frnd(1.0) < 0.5 {
+                    params.freq_ramp = 0.1 + frnd(0.4);
+                    params.repeat_speed = 0.4 + frnd(0.4);
+                } else {
+                    params.freq_ramp = 0.05 + frnd(0.2);
+                    if frnd(1.0) < 0.5 {
+                        params.vib_strength = frnd(0.7);
+                        params.vib_speed = frnd(0.6);
+                    }
+                }
+                params.env_attack = 0.0;
+                params.env_sustain = frnd(0.4);
+                params.env_decay = 0.1 + frnd(0.4);
+            }
+            Self::Explosion => {
+                params.waveform = Waveform::Noise;
+                if frnd(1.0) < 0.5 {
+                    params.base_freq = 0.1 + frnd(0.4);
+                    params.freq_ramp = -0.1 + frnd(0.4);
+                } else {
+                    params.base_freq = 0.2 + frnd(0.7);
+                    params.freq_ramp = -0.2 - frnd(0.2);
+                }
+                params.base_freq *= params.base_freq;
+                if frnd(1.0) < 0.2 {
+                    params.freq_ramp = 0.0;
+                }
+                if frnd(1.0) < 0.33 {
+                    params.repeat_speed = 0.3 + frnd(0.5);
+                }
+                params.env_attack = 0.0;
+                params.env_sustain = 0.1 + frnd(0.3);
+                params.env_decay = frnd(0.5);
+                if frnd(1.0) < 0.5 {
+                    params.pha_offset = -0.3 + frnd(0.9);
+                    params.pha_ramp = -frnd(0.3);
+                }
+                params.env_punch = 0.2 + frnd(0.6);
+                if frnd(1.0) < 0.5 {
+                    params.vib_strength = frnd(0.7);
+                    params.vib_speed = frnd(0.6);
+                }
+                if frnd(1.0) < 0.33 {
+                    params.arp_speed = 0.6 + frnd(0.3);
+                    params.arp_mod = 0.8 - frnd(1.6);
+                }
+            }
+        }
+
+        params
+    }
+}
+
+/// Parameters of an sfxr sound, with the same meaning and ranges as in sfxr and jsfxr
+///
+/// Values are in `0..=1`, except ramps, `arp_mod` and `pha_offset` which are in `-1..=1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SfxrParams {
+    pub waveform: Waveform,
+    pub env_attack: f32,
+    pub env_sustain: f32,
+    pub env_punch: f32,
+    pub env_decay: f32,
+    pub base_freq: f32,
+    /// Sounds stop when their frequency slides below this
+    pub freq_limit: f32,
+    pub freq_ramp: f32,
+    pub freq_dramp: f32,
+    pub vib_strength: f32,
+    pub vib_speed: f32,
+    pub arp_mod: f32,
+    pub arp_speed: f32,
+    pub duty: f32,
+    pub duty_ramp: f32,
+    pub repeat_speed: f32,
+    pub pha_offset: f32,
+    pub pha_ramp: f32,
+    pub lpf_freq: f32,
+    pub lpf_ramp: f32,
+    pub lpf_resonance: f32,
+    pub hpf_freq: f32,
+    pub hpf_ramp: f32,
+    pub volume: f32,
+}
+
+impl Default for SfxrParams {
+    fn default() -> Self {
+        Self {
+            waveform: Waveform::Square,
+            env_attack: 0.0,
+            env_sustain: 0.3,
+            env_punch: 0.0,
+            env_decay: 0.4,
+            base_freq: 0.3,
+            freq_limit: 0.0,
+            freq_ramp: 0.0,
+            freq_dramp: 0.0,
+            vib_strength: 0.0,
+            vib_speed: 0.0,
+            arp_mod: 0.0,
+            arp_speed: 0.0,
+            duty: 0.0,
+            duty_ramp: 0.0,
+            repeat_speed: 0.0,
+            pha_offset: 0.0,
+            pha_ramp: 0.0,
+            lpf_freq: 1.0,
+            lpf_ramp: 0.0,
+            lpf_resonance: 0.0,
+            hpf_freq: 0.0,
+            hpf_ramp: 0.0,
+            volume: 0.5,
+        }
+    }
+}
+
+impl SfxrParams {
+    /// Nudges every parameter a little, like the sfxr "mutate" button
+    #[must_use]
+    pub fn mutate(&self, seed: u64) -> Self {
+        let mut rng = Rng::with_seed(seed);
+        let mut mutated = self.clone();
+
+        let unsigned = [
+            &mut mutated.env_attack,
+            &mut mutated.env_sustain,
+            &mut mutated.env_punch,
+            &mut mutated.env_decay,
+            &mut mutated.base_freq,
+            &mut mutated.freq_limit,
+            &mut mutated.vib_strength,
+            &mut mutated.vib_speed,
+            &mut mutated.arp_speed,
+            &mut mutated.duty,
+            &mut mutated.repeat_speed,
+            &mut mutated.lpf_freq,
+            &mut mutated.lpf_resonance,
+            &mut mutated.hpf_freq,
+        ];
+        for value in unsigned {
+            if rng.bool() {
+                *value = rng.f32().mul_add(0.1, *value - 0.05).clamp(0.0, 1.0);
+            }
+        }
+
+        let signed = [
+            &mut mutated.freq_ramp,
+            &mut mutated.freq_dramp,
+            &mut mutated.arp_mod,
+            &mut mutated.duty_ramp,
+            &mut mutated.pha_offset,
+            &mut mutated.pha_ramp,
+            &mut mutated.lpf_ramp,
+            &mut mutated.hpf_ramp,
+        ];
+        for value in signed {
+            if rng.bool() {
+                *value = rng.f32().mul_add(0.1, *value - 0.05).clamp(-1.0, 1.0);
+            }
+        }
+
+        mutated
+    }
+
+    /// Renders the sound as mono samples in `-1..=1` at [`SAMPLE_RATE`]
+    pub fn synthesize(&self) -> Vec<f32> {
+        let mut synth = Synth::new(self);
+        let mut samples = Vec::new();
+        while samples.len() < MAX_SAMPLES {
+            let Some(sample) = synth.next_sample() else {
+                break;
+            };
+            samples.push(sample);
+        }
+        samples
+    }
+}
+
+/// Encodes mono samples in `-1..=1` as a 16-bit PCM WAV file
+pub fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data_size = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(44 + samples.len() * 2);
+
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_size).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    // PCM, mono
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    // Block align and bits per sample
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        let sample = (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16;
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    wav
+}
+
+/// Playback state of the sfxr generator
+struct Synth<'a> {
+    params: &'a SfxrParams,
+    rng: Rng,
+
+    phase: usize,
+    fperiod: f64,
+    fmaxperiod: f64,
+    fslide: f64,
+    fdslide: f64,
+    square_duty: f32,
+    square_slide: f32,
+    arp_mod: f64,
+    arp_time: usize,
+    arp_limit: usize,
+
+    env_stage: usize,
+    env_time: usize,
+    env_length: [usize; 3],
+
+    fphase: f32,
+    fdphase: f32,
+    iphase: usize,
+    ipp: usize,
+    phaser_buffer: Vec<f32>,
+    noise_buffer: [f32; NOISE_SIZE],
+
+    fltp: f32,
+    fltdp: f32,
+    fltw: f32,
+    fltw_d: f32,
+    fltdmp: f32,
+    fltphp: f32,
+    flthp: f32,
+    flthp_d: f32,
+
+    vib_phase: f32,
+    vib_speed: f32,
+    vib_amp: f32,
+
+    rep_time: usize,
+    rep_limit: usize,
+}
+
+impl<'a> Synth<'a> {
+    fn new(params: &'a SfxrParams) -> Self {
+        let mut synth = Self {
+            params,
+            // The noise waveform is part of the sound, so it does not vary between renders
+            rng: Rng::with_seed(0),
+            phase: 0,
+            fperiod: 0.0,
+            fmaxperiod: 0.0,
+            fslide: 0.0,
+            fdslide: 0.0,
+            square_duty: 0.0,
+            square_slide: 0.0,
+            arp_mod: 0.0,
+            arp_time: 0,
+            arp_limit: 0,
+            env_stage: 0,
+            env_time: 0,
+            env_length: [
+                (params.env_attack.powi(2) * 100_000.0) as usize,
+                (params.env_sustain.powi(2) * 100_000.0) as usize,
+                (params.env_decay.powi(2) * 100_000.0) as usize,
+            ],
+            fphase: params.pha_offset.powi(2).copysign(params.pha_offset) * 1020.0,
+            fdphase: params.pha_ramp.powi(2).copysign(params.pha_ramp),
+            iphase: 0,
+            ipp: 0,
+            phaser_buffer: vec![0.0; PHASER_SIZE],
+            noise_buffer: [0.0; NOISE_SIZE],
+            fltp: 0.0,
+            fltdp: 0.0,
+            fltw: params.lpf_freq.powi(3) * 0.1,
+            fltw_d: params.lpf_ramp.mul_add(0.0001, 1.0),
+            fltdmp: (5.0 / params.lpf_resonance.powi(2).mul_add(20.0, 1.0)
+                * params.lpf_freq.powi(3).mul_add(0.1, 0.01))
+            .min(0.8),
+            fltphp: 0.0,
+            flthp: params.hpf_freq.powi(2) * 0.1,
+            flthp_d: params.hpf_ramp.mul_add(0.0003, 1.0),
+            vib_phase: 0.0,
+            vib_speed: params.vib_speed.powi(2) * 0.01,
+            vib_amp: params.vib_strength * 0.5,
+            rep_time: 0,
+            rep_limit: if params.repeat_speed <= 0.0 {
+                0
+            } else {
+                (1.0 - params.repeat_speed).powi(2).mul_add(20_000.0, 32.0) as usize
+            },
+        };
+        synth.iphase = synth.fphase.abs() as usize;
+        synth.fill_noise();
+        synth.restart();
+        synth
+    }
+
+    /// Resets the pitch, which repeating sounds do periodically
+    fn restart(&mut self) {
+        let params = self.params;
+        self.fperiod = 100.0 / f64::from(params.base_freq.mul_add(params.base_freq, 0.001));
+        self.fmaxperiod = 100.0 / f64::from(params.freq_limit.mul_add(params.freq_limit, 0.001));
+        self.fslide = f64::from(params.freq_ramp.powi(3)).mul_add(-0.01, 1.0);
+        self.fdslide = -f64::from(params.freq_dramp.powi(3)) * 0.000001;
+        self.square_duty = params.duty.mul_add(-0.5, 0.5);
+        self.square_slide = -params.duty_ramp * 0.00005;
+        self.arp_mod = if params.arp_mod >= 0.0 {
+            f64::from(params.arp_mod.powi(2)).mul_add(-0.9, 1.0)
+        } else {
+            f64::from(params.arp_mod.powi(2)).mul_add(10.0, 1.0)
+        };
+        self.arp_time = 0;
+        self.arp_limit = if params.arp_speed >= 1.0 {
+            0
+        } else {
+            (1.0 - params.arp_speed).powi(2).mul_add(20_000.0, 32.0) as usize
+        };
+    }
+
+    fn fill_noise(&mut self) {
+        for noise in &mut self.noise_buffer {
+            *noise = self.rng.f32().mul_add(2.0, -1.0);
+        }
+    }
+
+    /// Computes the next sample, or `None` once the sound is over
+    fn next_sample(&mut self) -> Option<f32> {
+        let params = self.params;
+
+        self.rep_time += 1;
+        if self.rep_limit != 0 && self.rep_time >= self.rep_limit {
+            self.rep_time = 0;
+            self.restart();
+        }
+
+        self.arp_time += 1;
+        if self.arp_limit != 0 && self.arp_time >= self.arp_limit {
+            self.arp_limit = 0;
+            self.fperiod *= self.arp_mod;
+        }
+
+        self.fslide += self.fdslide;
+        self.fperiod *= self.fslide;
+        if self.fperiod > self.fmaxperiod {
+            self.fperiod = self.fmaxperiod;
+            if params.freq_limit > 0.0 {
+                return None;
+            }
+        }
+
+        let mut rfperiod = self.fperiod as f32;
+        if self.vib_amp > 0.0 {
+            self.vib_phase += self.vib_speed;
+            rfperiod *= self.vib_phase.sin().mul_add(self.vib_amp, 1.0);
+        }
+        let period = (rfperiod as usize).max(8);
+
+        self.square_duty = (self.square_duty + self.square_slide).clamp(0.0, 0.5);
+
+        self.env_time += 1;
+        let stage_length = |stage: usize| self.env_length.get(stage).copied().unwrap_or(0);
+        if self.env_time > stage_length(self.env_stage) {
+            self.env_time = 0;
+            self.env_stage += 1;
+            if self.env_stage == 3 {
+                return None;
+            }
+        }
+        let progress = self.env_time as f32 / stage_length(self.env_stage).max(1) as f32;
+        let env_volume = match self.env_stage {
+            0 => progress,
+            1 => ((1.0 - progress) * 2.0).mul_add(params.env_punch, 1.0),
+            _ => 1.0 - progress,
+        };
+
+        self.fphase += self.fdphase;
+        self.iphase = (self.fphase.abs() as usize).min(PHASER_SIZE - 1);
+
+        if self.flthp_d != 0.0 {
+            self.flthp = (self.flthp * self.flthp_d).clamp(0.00001, 0.1);
+        }
+
+        let mut supersample = 0.0;
+        for _ in 0..SUPERSAMPLES {
+            self.phase += 1;
+            if self.phase >= period {
+                self.phase %= period;
+                if params.waveform == Waveform::Noise {
+                    self.fill_noise();
+                }
+            }
+
+            let fp = self.phase as f32 / period as f32;
+            let mut sample = match params.waveform {
+                Waveform::Square if fp < self.square_duty => 0.5,
+                Waveform::Square => -0.5,
+                Waveform::Sawtooth => fp.mul_add(-2.0, 1.0),
+                Waveform::Sine => (fp * TAU).sin(),
+                Waveform::Noise => self
+                    .noise_buffer
+                    .get(self.phase * NOISE_SIZE / period)
+                    .copied()
+                    .unwrap_or(0.0),
+            };
+
+            // Low pass filter
+            let previous = self.fltp;
+            self.fltw = (self.fltw * self.fltw_d).clamp(0.0, 0.1);
+            if params.lpf_freq < 1.0 {
+                self.fltdp += (sample - self.fltp) * self.fltw;
+                self.fltdp -= self.fltdp * self.fltdmp;
+            } else {
+                self.fltp = sample;
+                self.fltdp = 0.0;
+            }
+            self.fltp += self.fltdp;
+
+            // High pass filter
+            self.fltphp += self.fltp - previous;
+            self.fltphp -= self.fltphp * self.flthp;
+            sample = self.fltphp;
+
+            // Phaser
+            if let Some(slot) = self.phaser_buffer.get_mut(self.ipp % PHASER_SIZE) {
+                *slot = sample;
+            }
+            sample += self
+                .phaser_buffer
+                .get((self.ipp + PHASER_SIZE - self.iphase) % PHASER_SIZE)
+                .copied()
+                .unwrap_or(0.0);
+            self.ipp = (self.ipp + 1) % PHASER_SIZE;
+
+            supersample += sample * env_volume;
+        }
+
+        let sample = supersample / SUPERSAMPLES as f32 * MASTER_VOLUME * 2.0 * params.volume;
+        Some(sample.clamp(-1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn presets_are_deterministic() {
+        for preset in SfxrPreset::iter() {
+            assert_eq!(
+                preset.generate(7).synthesize(),
+                preset.generate(7).synthesize(),
+                "{preset:?} should render the same samples for the same seed"
+            );
+        }
+
+        let seeds: Vec<_> = (0..8).map(|seed| SfxrPreset::Coin.generate(seed)).collect();
+        assert!(
+            seeds
+                .iter()
+                .skip(1)
+                .any(|params| Some(params) != seeds.first()),
+            "different seeds should give different coins"
+        );
+    }
+
+    #[test]
+    fn presets_render_audible_bounded_samples() {
+        for preset in SfxrPreset::iter() {
+            for seed in 0..20 {
+                let samples = preset.generate(seed).synthesize();
+
+                assert!(
+                    !samples.is_empty() && samples.len() <= MAX_SAMPLES,
+                    "{preset:?} seed {seed} rendered {} samples",
+                    samples.len()
+                );
+                assert!(
+                    samples.iter().all(|sample| (-1.0..=1.0).contains(sample)),
+                    "{preset:?} seed {seed} has samples out of range"
+                );
+                let peak = samples
+                    .iter()
+                    .fold(0.0_f32, |peak, sample| peak.max(sample.abs()));
+                assert!(peak > 0.01, "{preset:?} seed {seed} is silent");
+            }
+        }
+    }
+
+    #[test]
+    fn envelope_sets_length() {
+        let params = SfxrParams {
+            env_attack: 0.0,
+            env_sustain: 0.1,
+            env_decay: 0.2,
+            ..SfxrParams::default()
+        };
+        // The empty attack takes one sample, sustain and decay their length plus one
+        let expected = 1 + 1_001 + 4_001;
+        let length = params.synthesize().len();
+        assert!(
+            length.abs_diff(expected) <= 1,
+            "rendered {length} samples, expected about {expected}"
+        );
+    }
+
+    #[test]
+    fn frequency_limit_cuts_sliding_sounds() {
+        let sliding = SfxrParams {
+            freq_ramp: -0.5,
+            env_sustain: 1.0,
+            ..SfxrParams::default()
+        };
+        let sliding_length = sliding.synthesize().len();
+        let limited = SfxrParams {
+            freq_limit: 0.2,
+            ..sliding
+        };
+        assert!(
+            limited.synthesize().len() < sliding_length,
+            "the frequency limit should end the sound early"
+        );
+    }
+
+    #[test]
+    fn mutation_stays_in_range() {
+        let params = SfxrPreset::Explosion.generate(3);
+        for seed in 0..50 {
+            let mutated = params.mutate(seed);
+            assert_eq!(mutated, params.mutate(seed), "mutation should be seeded");
+            assert_eq!(
+                mutated.waveform, params.waveform,
+                "mutation should keep the waveform"
+            );
+            for value in [
+                mutated.base_freq,
+                mutated.duty,
+                mutated.lpf_freq,
+                mutated.hpf_freq,
+            ] {
+                assert!((0.0..=1.0).contains(&value), "{value} is out of 0..=1");
+            }
+            for value in [mutated.freq_ramp, mutated.arp_mod, mutated.pha_offset] {
+                assert!((-1.0..=1.0).contains(&value), "{value} is out of -1..=1");
+            }
+        }
+    }
+
+    #[test]
+    fn encodes_wav() {
+        let samples = [0.0, 1.0, -1.0, 0.5];
+        let wav = encode_wav(&samples, SAMPLE_RATE);
+
+        assert_eq!(wav.len(), 44 + samples.len() * 2, "unexpected file size");
+        assert_eq!(wav.get(0..4), Some(&b"RIFF"[..]), "missing RIFF header");
+        assert_eq!(
+            wav.get(8..16),
+            Some(&b"WAVEfmt "[..]),
+            "missing format chunk"
+        );
+        assert_eq!(
+            wav.get(24..28)
+                .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap())),
+            Some(SAMPLE_RATE),
+            "wrong sample rate"
+        );
+        assert_eq!(wav.get(36..40), Some(&b"data"[..]), "missing data chunk");
+
+        let decoded: Vec<i16> = wav
+            .chunks_exact(2)
+            .skip(22)
+            .map(|bytes| i16::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+        assert_eq!(
+            decoded,
+            [0, i16::MAX, -i16::MAX, i16::MAX / 2],
+            "wrong samples"
+        );
+    }
+}
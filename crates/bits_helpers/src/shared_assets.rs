@@ -0,0 +1,59 @@
+use bevy::asset::io::AssetSourceBuilder;
+use bevy::prelude::*;
+
+/// Name of the asset source serving the `assets` directory of `bits_helpers`
+///
+/// Shared assets are loaded with paths such as `bits://fonts/FiraSans-Bold.ttf`, which
+/// stay the same whatever the bit, the platform or the directory it runs from. Files
+/// added to `bits_helpers/assets` are available to every bit the same way.
+pub const SHARED_ASSETS: &str = "bits";
+
+/// Directory of the shared assets: the crate sources on native, and the published
+/// `bits_helpers` package next to the bits on wasm
+#[cfg(not(target_arch = "wasm32"))]
+const SHARED_ASSETS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets");
+
+#[cfg(target_arch = "wasm32")]
+const SHARED_ASSETS_DIR: &str = concat!("bits/bits_helpers-", env!("CARGO_PKG_VERSION"), "/assets");
+
+/// Registers the [`SHARED_ASSETS`] source, must be added before [`AssetPlugin`]
+///
+/// [`get_default_app`](crate::get_default_app) already adds it.
+pub struct SharedAssetsPlugin;
+
+impl Plugin for SharedAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_asset_source(
+            SHARED_ASSETS,
+            AssetSourceBuilder::platform_default(SHARED_ASSETS_DIR, None),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use bevy::tasks::{IoTaskPool, block_on};
+
+    use super::*;
+    use crate::FONT;
+
+    #[test]
+    fn shared_assets_resolve() {
+        IoTaskPool::get_or_init(Default::default);
+        let mut app = App::new();
+        app.add_plugins((SharedAssetsPlugin, AssetPlugin::default()));
+
+        let asset_server = app.world().resource::<AssetServer>();
+        let source = asset_server
+            .get_source(SHARED_ASSETS)
+            .expect("shared assets are registered");
+
+        let font = FONT
+            .strip_prefix("bits://")
+            .expect("the font is a shared asset");
+        let read = block_on(source.reader().read(Path::new(font)));
+        assert!(read.is_ok(), "{FONT} is found");
+    }
+}
@@ -0,0 +1,421 @@
+use core::time::Duration;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use thiserror::Error;
+
+const HIGH_SCORE_KEY: &str = "high_score";
+const BEST_TIME_KEY: &str = "best_time";
+const SETTINGS_KEY: &str = "settings";
+
+/// Persists values between sessions under the bit's own namespace
+///
+/// Values are stored in a JSON file under the user data directory on native,
+/// or in the browser `localStorage` on wasm.
+pub struct StoragePlugin {
+    pub namespace: String,
+}
+
+impl Plugin for StoragePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Storage>() {
+            app.insert_resource(Storage::new(&self.namespace, default_backend()));
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum StorageError {
+    #[error("Could not access the storage file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not encode or decode a stored value: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Browser storage is unavailable")]
+    Unavailable,
+}
+
+/// Where stored values end up
+pub trait StorageBackend: Send + Sync {
+    fn load(&self, key: &str) -> Result<Option<Value>, StorageError>;
+    fn store(&self, key: &str, value: Value) -> Result<(), StorageError>;
+    fn remove(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// Keeps values for the lifetime of the process, for tests and as a fallback
+#[derive(Default)]
+pub struct MemoryStorage {
+    values: Mutex<HashMap<String, Value>>,
+}
+
+impl StorageBackend for MemoryStorage {
+    fn load(&self, key: &str) -> Result<Option<Value>, StorageError> {
+        Ok(self.values.lock().get(key).cloned())
+    }
+
+    fn store(&self, key: &str, value: Value) -> Result<(), StorageError> {
+        self.values.lock().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        self.values.lock().remove(key);
+        Ok(())
+    }
+}
+
+/// Keeps every value in a single JSON object, rewritten on each change
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorage {
+    path: std::path::PathBuf,
+    values: Mutex<serde_json::Map<String, Value>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    /// Opens the storage file at `path`, starting empty if it does not exist yet
+    pub fn open(path: impl Into<std::path::PathBuf>) -> Result<Self, StorageError> {
+        let path = path.into();
+        let values = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => serde_json::Map::new(),
+            Err(error) => return Err(error.into()),
+        };
+
+        Ok(Self {
+            path,
+            values: Mutex::new(values),
+        })
+    }
+
+    /// `ribbit_bits/storage.json` under the user data directory, or under
+    /// the directory given by the `data_dir` launch option
+    pub fn default_path() -> Option<std::path::PathBuf> {
+        use std::env::var_os;
+        use std::path::PathBuf;
+
+        if let Some(dir) = crate::launch_options::launch_option("data_dir") {
+            return Some(PathBuf::from(dir).join("storage.json"));
+        }
+
+        let data_dir = if cfg!(target_os = "windows") {
+            var_os("APPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+        } else {
+            var_os("XDG_DATA_HOME")
+                .map(PathBuf::from)
+                .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        }?;
+
+        Some(data_dir.join("ribbit_bits").join("storage.json"))
+    }
+
+    fn save(&self, values: &serde_json::Map<String, Value>) -> Result<(), StorageError> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        // Write then rename, so a crash never leaves a truncated file behind
+        let temporary = self.path.with_extension("json.tmp");
+        std::fs::write(&temporary, serde_json::to_string_pretty(values)?)?;
+        std::fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileStorage {
+    fn load(&self, key: &str) -> Result<Option<Value>, StorageError> {
+        Ok(self.values.lock().get(key).cloned())
+    }
+
+    #[expect(
+        clippy::significant_drop_tightening,
+        reason = "the lock is held while saving so that writes land in order"
+    )]
+    fn store(&self, key: &str, value: Value) -> Result<(), StorageError> {
+        let mut values = self.values.lock();
+        values.insert(key.to_string(), value);
+        self.save(&values)
+    }
+
+    #[expect(
+        clippy::significant_drop_tightening,
+        reason = "the lock is held while saving so that writes land in order"
+    )]
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        let mut values = self.values.lock();
+        if values.remove(key).is_some() {
+            self.save(&values)?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps each value as a JSON string in the browser `localStorage`
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorage {
+    fn storage() -> Result<web_sys::Storage, StorageError> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or(StorageError::Unavailable)
+    }
+
+    pub fn is_available() -> bool {
+        Self::storage().is_ok()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl StorageBackend for LocalStorage {
+    fn load(&self, key: &str) -> Result<Option<Value>, StorageError> {
+        let Some(json) = Self::storage()?
+            .get_item(key)
+            .map_err(|_error| StorageError::Unavailable)?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_str(&json)?))
+    }
+
+    fn store(&self, key: &str, value: Value) -> Result<(), StorageError> {
+        Self::storage()?
+            .set_item(key, &serde_json::to_string(&value)?)
+            .map_err(|_error| StorageError::Unavailable)
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        Self::storage()?
+            .remove_item(key)
+            .map_err(|_error| StorageError::Unavailable)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_backend() -> Arc<dyn StorageBackend> {
+    match FileStorage::default_path().map(FileStorage::open) {
+        Some(Ok(storage)) => Arc::new(storage),
+        Some(Err(error)) => {
+            error!("Could not open the storage file, values will not persist: {error}");
+            Arc::new(MemoryStorage::default())
+        }
+        None => {
+            warn!("No user data directory, values will not persist");
+            Arc::new(MemoryStorage::default())
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn default_backend() -> Arc<dyn StorageBackend> {
+    if LocalStorage::is_available() {
+        Arc::new(LocalStorage)
+    } else {
+        warn!("Browser storage is unavailable, values will not persist");
+        Arc::new(MemoryStorage::default())
+    }
+}
+
+/// Typed access to the values a bit persists
+///
+/// Keys are scoped to the bit, so bits sharing a backend never see each other's values.
+/// Failures are logged rather than returned, as a bit should play on without its records.
+#[derive(Resource, Clone)]
+pub struct Storage {
+    namespace: String,
+    backend: Arc<dyn StorageBackend>,
+}
+
+impl Storage {
+    pub fn new(namespace: &str, backend: Arc<dyn StorageBackend>) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            backend,
+        }
+    }
+
+    /// A storage that forgets everything when dropped
+    pub fn in_memory(namespace: &str) -> Self {
+        Self::new(namespace, Arc::new(MemoryStorage::default()))
+    }
+
+    fn scoped(&self, key: &str) -> String {
+        format!("{}.{key}", self.namespace)
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self
+            .backend
+            .load(&self.scoped(key))
+            .map_err(|error| warn!("Could not read {key}: {error}"))
+            .ok()??;
+
+        serde_json::from_value(value)
+            .map_err(|error| warn!("Ignoring stored {key}, it has an unexpected shape: {error}"))
+            .ok()
+    }
+
+    pub fn set<T: Serialize>(&self, key: &str, value: &T) {
+        let result = serde_json::to_value(value)
+            .map_err(StorageError::from)
+            .and_then(|value| self.backend.store(&self.scoped(key), value));
+        if let Err(error) = result {
+            error!("Could not save {key}: {error}");
+        }
+    }
+
+    pub fn remove(&self, key: &str) {
+        if let Err(error) = self.backend.remove(&self.scoped(key)) {
+            error!("Could not remove {key}: {error}");
+        }
+    }
+
+    pub fn high_score(&self) -> Option<u32> {
+        self.get(HIGH_SCORE_KEY)
+    }
+
+    /// Records `score` if it beats the high score, returning whether it did
+    pub fn submit_score(&self, score: u32) -> bool {
+        let is_record = self
+            .high_score()
+            .is_none_or(|high_score| score > high_score);
+        if is_record {
+            self.set(HIGH_SCORE_KEY, &score);
+        }
+        is_record
+    }
+
+    pub fn best_time(&self) -> Option<Duration> {
+        self.get(BEST_TIME_KEY)
+    }
+
+    /// Records `time` if it is faster than the best time, returning whether it was
+    pub fn submit_fastest_time(&self, time: Duration) -> bool {
+        let is_record = self.best_time().is_none_or(|best_time| time < best_time);
+        if is_record {
+            self.set(BEST_TIME_KEY, &time);
+        }
+        is_record
+    }
+
+    /// Records `time` if it is longer than the best time, returning whether it was
+    pub fn submit_longest_time(&self, time: Duration) -> bool {
+        let is_record = self.best_time().is_none_or(|best_time| time > best_time);
+        if is_record {
+            self.set(BEST_TIME_KEY, &time);
+        }
+        is_record
+    }
+
+    /// Reads the bit's settings, or their defaults if none were saved
+    pub fn settings<T: DeserializeOwned + Default>(&self) -> T {
+        self.get(SETTINGS_KEY).unwrap_or_default()
+    }
+
+    pub fn save_settings<T: Serialize>(&self, settings: &T) {
+        self.set(SETTINGS_KEY, settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Settings {
+        muted: bool,
+        difficulty: u8,
+    }
+
+    #[test]
+    fn keeps_score_and_time_records() {
+        let storage = Storage::in_memory("test_bit");
+
+        assert_eq!(storage.high_score(), None, "no score was submitted yet");
+        assert!(storage.submit_score(10), "the first score is a record");
+        assert!(!storage.submit_score(5), "a lower score is not a record");
+        assert!(!storage.submit_score(10), "a tie is not a record");
+        assert!(storage.submit_score(12), "a higher score is a record");
+        assert_eq!(storage.high_score(), Some(12), "the highest score is kept");
+
+        assert!(
+            storage.submit_fastest_time(Duration::from_secs(30)),
+            "first time"
+        );
+        assert!(
+            !storage.submit_fastest_time(Duration::from_secs(40)),
+            "slower"
+        );
+        assert!(
+            storage.submit_fastest_time(Duration::from_secs(20)),
+            "faster"
+        );
+        assert_eq!(
+            storage.best_time(),
+            Some(Duration::from_secs(20)),
+            "the fastest time is kept"
+        );
+    }
+
+    #[test]
+    fn scopes_keys_per_bit() {
+        let backend: Arc<dyn StorageBackend> = Arc::new(MemoryStorage::default());
+        let first = Storage::new("first", backend.clone());
+        let second = Storage::new("second", backend);
+
+        first.submit_score(3);
+        assert_eq!(second.high_score(), None, "bits should not share records");
+
+        let settings = Settings {
+            muted: true,
+            difficulty: 2,
+        };
+        second.save_settings(&settings);
+        assert_eq!(
+            second.settings::<Settings>(),
+            settings,
+            "settings round trip"
+        );
+        assert_eq!(
+            first.settings::<Settings>(),
+            Settings::default(),
+            "missing settings fall back to their defaults"
+        );
+    }
+
+    #[test]
+    fn ignores_values_of_another_shape() {
+        let storage = Storage::in_memory("test_bit");
+        storage.set(HIGH_SCORE_KEY, &"not a number");
+
+        assert_eq!(storage.high_score(), None, "malformed records are ignored");
+        assert!(storage.submit_score(1), "malformed records are replaced");
+
+        storage.remove(HIGH_SCORE_KEY);
+        assert_eq!(storage.high_score(), None, "removed records are gone");
+    }
+
+    #[test]
+    fn file_storage_persists_between_sessions() {
+        let dir = std::env::temp_dir().join(format!("bits_storage_{}", std::process::id()));
+        let path = dir.join("storage.json");
+
+        let storage = Storage::new("test_bit", Arc::new(FileStorage::open(&path).unwrap()));
+        storage.submit_score(42);
+
+        let reopened = Storage::new("test_bit", Arc::new(FileStorage::open(&path).unwrap()));
+        assert_eq!(reopened.high_score(), Some(42), "the score should persist");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
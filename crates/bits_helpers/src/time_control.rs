@@ -0,0 +1,139 @@
+use bevy::core::FrameCount;
+use bevy::prelude::*;
+use bevy::time::TimeSystem;
+
+use crate::FONT;
+use crate::launch_options::{launch_flag, launch_option};
+
+const PAUSE_KEY: KeyCode = KeyCode::F5;
+const STEP_KEY: KeyCode = KeyCode::F6;
+const SLOWER_KEY: KeyCode = KeyCode::F7;
+const FASTER_KEY: KeyCode = KeyCode::F8;
+
+const MIN_TIME_SCALE: f32 = 0.125;
+const MAX_TIME_SCALE: f32 = 8.0;
+
+const INDICATOR_FONT_SIZE: f32 = 14.0;
+const INDICATOR_MARGIN: f32 = 4.0;
+
+/// Slows down, speeds up and pauses the bit through [`Time<Virtual>`], so every system
+/// reading [`Time`] follows, including the simulation's timer
+///
+/// - `F5` pauses and resumes
+/// - `F6` advances a single frame while paused
+/// - `F7` and `F8` halve and double the speed, from x0.125 to x8
+///
+/// The `time_scale` launch option sets the starting speed, e.g. `BITS_TIME_SCALE=0.5`,
+/// and the `paused` option starts the bit paused.
+pub struct TimeControlPlugin;
+
+impl Plugin for TimeControlPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameStep>()
+            .add_systems(Startup, (apply_launch_options, spawn_indicator))
+            .add_systems(Update, (control_time, update_indicator).chain())
+            .add_systems(First, end_frame_step.after(TimeSystem));
+    }
+}
+
+/// Frame on which a single step was requested, time being paused again after it
+#[derive(Resource, Default)]
+struct FrameStep(Option<u32>);
+
+#[derive(Component)]
+struct TimeScaleIndicator;
+
+fn apply_launch_options(mut time: ResMut<Time<Virtual>>) {
+    if let Some(scale) = launch_option("time_scale") {
+        match scale.parse::<f32>() {
+            Ok(scale) if scale > 0.0 => {
+                time.set_relative_speed(scale.clamp(MIN_TIME_SCALE, MAX_TIME_SCALE));
+            }
+            _ => warn!("Ignored time_scale {scale}, expected a positive number"),
+        }
+    }
+    if launch_flag("paused") {
+        time.pause();
+    }
+}
+
+fn spawn_indicator(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        TimeScaleIndicator,
+        Text::default(),
+        TextFont {
+            font: asset_server.load(FONT),
+            font_size: INDICATOR_FONT_SIZE,
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(INDICATOR_MARGIN),
+            right: Val::Px(INDICATOR_MARGIN),
+            ..default()
+        },
+        GlobalZIndex(i32::MAX - 1),
+    ));
+}
+
+fn control_time(
+    keys: Res<ButtonInput<KeyCode>>,
+    frame: Res<FrameCount>,
+    mut time: ResMut<Time<Virtual>>,
+    mut step: ResMut<FrameStep>,
+) {
+    if keys.just_pressed(PAUSE_KEY) {
+        if time.is_paused() {
+            time.unpause();
+        } else {
+            time.pause();
+        }
+        step.0 = None;
+    }
+    if keys.just_pressed(STEP_KEY) && time.is_paused() {
+        time.unpause();
+        step.0 = Some(frame.0);
+    }
+
+    let speed = time.relative_speed();
+    if keys.just_pressed(SLOWER_KEY) {
+        time.set_relative_speed((speed / 2.0).max(MIN_TIME_SCALE));
+    }
+    if keys.just_pressed(FASTER_KEY) {
+        time.set_relative_speed((speed * 2.0).min(MAX_TIME_SCALE));
+    }
+}
+
+/// Pauses again once time advanced for the stepped frame, the frame still running with
+/// that delta
+fn end_frame_step(
+    frame: Res<FrameCount>,
+    mut time: ResMut<Time<Virtual>>,
+    mut step: ResMut<FrameStep>,
+) {
+    if step.0.is_some_and(|requested| frame.0 > requested) {
+        time.pause();
+        step.0 = None;
+    }
+}
+
+fn update_indicator(
+    time: Res<Time<Virtual>>,
+    step: Res<FrameStep>,
+    mut indicators: Query<&mut Text, With<TimeScaleIndicator>>,
+) {
+    let speed = time.relative_speed();
+    let label = if time.is_paused() || step.0.is_some() {
+        format!("Paused x{speed}, {STEP_KEY:?} to step")
+    } else if (speed - 1.0).abs() > f32::EPSILON {
+        format!("x{speed}")
+    } else {
+        String::new()
+    };
+
+    for mut text in &mut indicators {
+        if text.0 != label {
+            text.0.clone_from(&label);
+        }
+    }
+}
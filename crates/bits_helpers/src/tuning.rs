@@ -0,0 +1,230 @@
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+use std::path::Path;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer};
+use thiserror::Error;
+
+/// Tunable values of a bit, loaded from a `.tuning.ron` or `.tuning.json` asset
+pub trait Tuning: Resource + Clone + DeserializeOwned + TypePath {
+    /// Checks the values are usable, invalid files are rejected and the previous values kept
+    fn validate(&self) -> Result<(), TuningError> {
+        Ok(())
+    }
+}
+
+/// Inserts a [`Tuning`] resource read from an asset, so designers can tweak a bit
+/// without recompiling
+///
+/// The same file is embedded as `defaults`, which are used until the asset is loaded.
+/// With the `dev` feature the asset is watched on native, and edits are applied to the
+/// resource while the bit runs:
+///
+/// ```ignore
+/// app.add_plugins(TuningPlugin::<GameVariables>::new(
+///     "variables.tuning.ron",
+///     include_str!("../assets/variables.tuning.ron"),
+/// ));
+/// ```
+pub struct TuningPlugin<T> {
+    path: &'static str,
+    defaults: &'static str,
+    tuning: PhantomData<fn() -> T>,
+}
+
+impl<T> TuningPlugin<T> {
+    pub const fn new(path: &'static str, defaults: &'static str) -> Self {
+        Self {
+            path,
+            defaults,
+            tuning: PhantomData,
+        }
+    }
+}
+
+impl<T: Tuning> Plugin for TuningPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let defaults = parse::<T>(Path::new(self.path), self.defaults.as_bytes())
+            .and_then(|tuning| tuning.validate().map(|()| tuning))
+            .expect("Embedded tuning should be valid");
+
+        app.init_asset::<TuningAsset<T>>()
+            .register_asset_loader(TuningLoader::<T>(PhantomData));
+
+        let handle = app.world().resource::<AssetServer>().load(self.path);
+        app.insert_resource(defaults)
+            .insert_resource(TuningHandle::<T>(handle))
+            .add_systems(PreUpdate, apply_tuning::<T>);
+    }
+}
+
+/// Checks a tuning value lies in `range`
+pub fn check_range<V: PartialOrd + Debug>(
+    field: &'static str,
+    value: V,
+    range: impl RangeBounds<V> + Debug,
+) -> Result<(), TuningError> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(TuningError::OutOfRange {
+            field,
+            value: format!("{value:?}"),
+            range: format!("{range:?}"),
+        })
+    }
+}
+
+/// Deserializes a [`Color`] from an sRGB hex string such as `"#FF0000"`, to be used
+/// with `#[serde(deserialize_with = "hex_color")]`
+pub fn hex_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    Srgba::hex(&hex).map(Color::from).map_err(D::Error::custom)
+}
+
+#[derive(Error, Debug)]
+pub enum TuningError {
+    #[error("Could not read tuning: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse tuning: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Could not parse tuning: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{field} is {value}, expected {range}")]
+    OutOfRange {
+        field: &'static str,
+        value: String,
+        range: String,
+    },
+}
+
+#[derive(Asset, TypePath)]
+pub struct TuningAsset<T: Tuning>(pub T);
+
+pub struct TuningLoader<T>(PhantomData<fn() -> T>);
+
+impl<T: Tuning> AssetLoader for TuningLoader<T> {
+    type Asset = TuningAsset<T>;
+    type Settings = ();
+    type Error = TuningError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let tuning = parse::<T>(load_context.path(), &bytes)?;
+        tuning.validate()?;
+        Ok(TuningAsset(tuning))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tuning.ron", "tuning.json"]
+    }
+}
+
+fn parse<T: DeserializeOwned>(path: &Path, bytes: &[u8]) -> Result<T, TuningError> {
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        Ok(serde_json::from_slice(bytes)?)
+    } else {
+        Ok(ron::de::from_bytes(bytes)?)
+    }
+}
+
+#[derive(Resource)]
+struct TuningHandle<T: Tuning>(Handle<TuningAsset<T>>);
+
+fn apply_tuning<T: Tuning>(
+    mut events: EventReader<AssetEvent<TuningAsset<T>>>,
+    handle: Res<TuningHandle<T>>,
+    assets: Res<Assets<TuningAsset<T>>>,
+    mut tuning: ResMut<T>,
+) {
+    for event in events.read() {
+        let (AssetEvent::Added { id } | AssetEvent::Modified { id }) = event else {
+            continue;
+        };
+        if *id != handle.0.id() {
+            continue;
+        }
+        if let Some(TuningAsset(loaded)) = assets.get(*id) {
+            info!("Applied tuning {}", T::short_type_path());
+            *tuning = loaded.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Clone, Debug, Deserialize, TypePath)]
+    struct TestTuning {
+        speed: f32,
+        lives: u32,
+        #[serde(deserialize_with = "hex_color")]
+        color: Color,
+    }
+
+    impl Tuning for TestTuning {
+        fn validate(&self) -> Result<(), TuningError> {
+            check_range("speed", self.speed, 0.0..=500.0)?;
+            check_range("lives", self.lives, 1..)
+        }
+    }
+
+    #[test]
+    fn parses_ron_and_json() {
+        let ron: TestTuning = parse(
+            Path::new("test.tuning.ron"),
+            br##"(speed: 120, lives: 3, color: "#FF0000")"##,
+        )
+        .unwrap();
+        let json: TestTuning = parse(
+            Path::new("test.tuning.json"),
+            br##"{"speed": 120, "lives": 3, "color": "#FF0000"}"##,
+        )
+        .unwrap();
+
+        for tuning in [ron, json] {
+            assert!(
+                (tuning.speed - 120.0).abs() < f32::EPSILON,
+                "speed is parsed"
+            );
+            assert_eq!(tuning.lives, 3, "lives are parsed");
+            assert_eq!(tuning.color, Color::srgb(1.0, 0.0, 0.0), "color is parsed");
+        }
+    }
+
+    #[test]
+    fn rejects_values_out_of_range() {
+        let tuning: TestTuning = parse(
+            Path::new("test.tuning.ron"),
+            br##"(speed: 900, lives: 3, color: "#FFFFFF")"##,
+        )
+        .unwrap();
+
+        let error = tuning.validate().unwrap_err();
+        assert_eq!(error.to_string(), "speed is 900.0, expected 0.0..=500.0");
+    }
+
+    #[test]
+    fn rejects_invalid_colors() {
+        let tuning = parse::<TestTuning>(
+            Path::new("test.tuning.ron"),
+            br#"(speed: 1, lives: 3, color: "red")"#,
+        );
+        assert!(tuning.is_err(), "colors must be hex");
+    }
+}
@@ -0,0 +1,217 @@
+use bevy::prelude::*;
+use bevy::render::camera::{CameraUpdateSystem, RenderTarget, ScalingMode, Viewport};
+use bevy::window::{PrimaryWindow, WindowRef};
+
+use crate::{WINDOW_HEIGHT, WINDOW_WIDTH};
+
+/// Keeps bits laid out in a fixed logical space, whatever the window size
+///
+/// 2D cameras on the primary window show exactly `size` world units, scaled to fit
+/// the window minus its safe area and centered. The rest of the window is left as
+/// letterbox/pillarbox bars of the clear color. UI is scaled by the same factor,
+/// so `Val::Px` values are virtual pixels too.
+pub struct VirtualResolutionPlugin {
+    pub size: Vec2,
+}
+
+impl Default for VirtualResolutionPlugin {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        }
+    }
+}
+
+impl Plugin for VirtualResolutionPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(VirtualResolution::new(self.size))
+            .init_resource::<SafeAreaInsets>()
+            .add_systems(
+                PostUpdate,
+                fit_virtual_resolution.before(CameraUpdateSystem),
+            );
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(PreUpdate, read_browser_safe_area);
+    }
+}
+
+/// Parts of the window hidden by notches, rounded corners or system bars, in logical pixels
+///
+/// On wasm they are read from the `--safe-area-inset-{top,right,bottom,left}` CSS variables
+/// of the page root, which the hosting page sets to the matching `env(safe-area-inset-*)`.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct SafeAreaInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+/// Where the virtual space lands in the window
+///
+/// Virtual positions are in virtual pixels from the top left, like window positions.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct VirtualResolution {
+    size: Vec2,
+    scale: f32,
+    viewport: Rect,
+}
+
+impl VirtualResolution {
+    fn new(size: Vec2) -> Self {
+        Self {
+            size,
+            scale: 1.0,
+            viewport: Rect::from_corners(Vec2::ZERO, size),
+        }
+    }
+
+    /// Size of the virtual space, in virtual pixels and world units
+    pub const fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Logical window pixels per virtual pixel
+    pub const fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Part of the window showing the virtual space, in logical window pixels
+    pub const fn viewport(&self) -> Rect {
+        self.viewport
+    }
+
+    /// Converts a window position, e.g. from a cursor or a touch, to a virtual position
+    ///
+    /// Returns `None` for positions over the bars.
+    pub fn window_to_virtual(&self, position: Vec2) -> Option<Vec2> {
+        self.viewport
+            .contains(position)
+            .then(|| (position - self.viewport.min) / self.scale)
+    }
+
+    pub fn virtual_to_window(&self, position: Vec2) -> Vec2 {
+        position.mul_add(Vec2::splat(self.scale), self.viewport.min)
+    }
+
+    /// Converts a virtual position to world coordinates seen by a camera at the origin
+    pub fn virtual_to_world(&self, position: Vec2) -> Vec2 {
+        (position - self.size * 0.5) * Vec2::new(1.0, -1.0)
+    }
+
+    /// Converts world coordinates seen by a camera at the origin to a virtual position
+    pub fn world_to_virtual(&self, position: Vec2) -> Vec2 {
+        position.mul_add(Vec2::new(1.0, -1.0), self.size * 0.5)
+    }
+
+    /// Fits the virtual space in the window, inside the safe area
+    fn fit(&mut self, window_size: Vec2, insets: &SafeAreaInsets) {
+        let min = Vec2::new(insets.left, insets.top);
+        let available = (window_size - min - Vec2::new(insets.right, insets.bottom)).max(Vec2::ONE);
+        self.scale = (available / self.size).min_element();
+
+        let size = self.size * self.scale;
+        let origin = min + (available - size) * 0.5;
+        self.viewport = Rect::from_corners(origin, origin + size);
+    }
+}
+
+fn fit_virtual_resolution(
+    mut resolution: ResMut<VirtualResolution>,
+    insets: Res<SafeAreaInsets>,
+    mut ui_scale: ResMut<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Camera, &mut OrthographicProjection), With<Camera2d>>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+
+    let mut fitted = *resolution;
+    fitted.fit(window.size(), &insets);
+    resolution.set_if_neq(fitted);
+    if (ui_scale.0 - fitted.scale).abs() > f32::EPSILON {
+        ui_scale.0 = fitted.scale;
+    }
+
+    // The viewport must stay inside the window once rounded to physical pixels
+    let scale_factor = window.scale_factor();
+    let window_size = window.physical_size();
+    let position = (fitted.viewport.min * scale_factor)
+        .round()
+        .as_uvec2()
+        .min(window_size.saturating_sub(UVec2::ONE));
+    let size = (fitted.viewport.size() * scale_factor)
+        .round()
+        .as_uvec2()
+        .clamp(UVec2::ONE, (window_size - position).max(UVec2::ONE));
+
+    for (mut camera, mut projection) in &mut cameras {
+        if !matches!(camera.target, RenderTarget::Window(WindowRef::Primary)) {
+            continue;
+        }
+
+        let is_fitted = camera.viewport.as_ref().is_some_and(|viewport| {
+            viewport.physical_position == position && viewport.physical_size == size
+        });
+        if !is_fitted {
+            camera.viewport = Some(Viewport {
+                physical_position: position,
+                physical_size: size,
+                ..default()
+            });
+        }
+
+        let is_scaled = matches!(
+            projection.scaling_mode,
+            ScalingMode::Fixed { width, height } if Vec2::new(width, height) == fitted.size
+        );
+        if !is_scaled {
+            projection.scaling_mode = ScalingMode::Fixed {
+                width: fitted.size.x,
+                height: fitted.size.y,
+            };
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_browser_safe_area(
+    mut insets: ResMut<SafeAreaInsets>,
+    mut resized: EventReader<bevy::window::WindowResized>,
+    mut is_read: Local<bool>,
+) {
+    if resized.read().count() == 0 && *is_read {
+        return;
+    }
+    *is_read = true;
+
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(root) = window
+        .document()
+        .and_then(|document| document.document_element())
+    else {
+        return;
+    };
+    let Ok(Some(style)) = window.get_computed_style(&root) else {
+        return;
+    };
+
+    let read = |side: &str| {
+        style
+            .get_property_value(&format!("--safe-area-inset-{side}"))
+            .ok()
+            .and_then(|value| value.trim().trim_end_matches("px").parse().ok())
+            .unwrap_or(0.0)
+    };
+
+    insets.set_if_neq(SafeAreaInsets {
+        top: read("top"),
+        right: read("right"),
+        bottom: read("bottom"),
+        left: read("left"),
+    });
+}
@@ -1,11 +1,17 @@
 use bevy::prelude::*;
 
+use crate::i18n::Strings;
 use crate::{FONT, WINDOW_HEIGHT, WINDOW_WIDTH};
 
 #[derive(Component)]
 pub struct WelcomeScreenElement;
 
-pub fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>, action: &str) {
+pub fn spawn_welcome_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    strings: &Strings,
+    action: &str,
+) {
     // Background
     commands.spawn((
         Sprite::from_color(Color::BLACK, Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT)),
@@ -33,7 +39,7 @@ pub fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServe
 
     // "Tap to start" text
     commands.spawn((
-        Text::new("Tap to start"),
+        Text::new(strings.get("welcome.tap_to_start")),
         TextFont {
             font: asset_server.load(FONT),
             font_size: 30.0,
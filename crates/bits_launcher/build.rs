@@ -0,0 +1,126 @@
+use std::path::Path;
+use std::{env, fs};
+
+use cargo_metadata::MetadataCommand;
+use quote::{format_ident, quote};
+use serde_json::Value;
+
+/// Values of `result` in `[package.metadata.bit]`, named after the variants of `BitResult`,
+/// with how the launcher lists them
+const RESULT_KINDS: &[(&str, &str)] = &[
+    ("highest_score", "highest score"),
+    ("lowest_score", "lowest score"),
+    ("longest_duration", "longest time"),
+    ("fastest_duration", "fastest time"),
+    ("success", "success or failure"),
+];
+
+fn main() {
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    if target_arch == "wasm32" {
+        return;
+    }
+
+    // Only workspace members are needed, their metadata is read from their manifest
+    let metadata = MetadataCommand::new()
+        .no_deps()
+        .exec()
+        .expect("Failed to get cargo metadata");
+
+    let mut bits: Vec<_> = metadata
+        .workspace_packages()
+        .into_iter()
+        .filter_map(|package| Some((package, package.metadata.get("bit")?)))
+        .collect();
+    bits.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+
+    let entries = bits.iter().map(|(package, bit)| {
+        let name = &package.name;
+        let module = format_ident!("{}", name.replace('-', "_"));
+        let version = package.version.to_string();
+        let description = package.description.clone().unwrap_or_default();
+        let manifest_dir = package
+            .manifest_path
+            .parent()
+            .expect("Failed to get parent directory")
+            .to_string();
+
+        let result = bit
+            .get("result")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        assert!(
+            RESULT_KINDS.iter().any(|&(kind, _)| kind == result),
+            "{name}: `result` in [package.metadata.bit] should be one of {RESULT_KINDS:?}"
+        );
+        let result = result_variant(result);
+
+        let duration = bit.get("duration");
+        let seconds = duration.and_then(Value::as_u64);
+        assert!(
+            seconds.is_some() || duration.and_then(Value::as_str) == Some("max"),
+            "{name}: `duration` in [package.metadata.bit] should be seconds or \"max\""
+        );
+        let duration = seconds.map_or_else(
+            || quote! { None },
+            |seconds| quote! { Some(Duration::from_secs(#seconds)) },
+        );
+
+        quote! {
+            BitInfo {
+                name: #name,
+                version: #version,
+                description: #description,
+                result: ResultKind::#result,
+                duration: #duration,
+                manifest_dir: #manifest_dir,
+                run: #module::run,
+            },
+        }
+    });
+
+    // Only the kinds some bit reports are variants, so none of them is dead code
+    let (variants, labels): (Vec<_>, Vec<_>) = RESULT_KINDS
+        .iter()
+        .filter(|&&(kind, _)| {
+            bits.iter()
+                .any(|(_, bit)| bit.get("result").and_then(Value::as_str) == Some(kind))
+        })
+        .map(|&(kind, label)| (result_variant(kind), label))
+        .unzip();
+
+    let generated_code = quote! {
+        /// What a bit reports when it ends, after the variants of `BitResult`
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum ResultKind {
+            #(#variants,)*
+        }
+
+        impl ResultKind {
+            pub const fn label(self) -> &'static str {
+                match self {
+                    #(Self::#variants => #labels,)*
+                }
+            }
+        }
+
+        pub const BITS: &[BitInfo] = &[
+            #(#entries)*
+        ];
+    };
+
+    let out_dir = env::var_os("OUT_DIR").expect("There should be an OUT_DIR");
+    let dest_path = Path::new(&out_dir).join("bits.rs");
+    let generated_file = syn::parse2(generated_code).expect("Failed to parse generated code");
+    fs::write(&dest_path, prettyplease::unparse(&generated_file))
+        .expect("Failed to write generated code");
+
+    for (package, _) in &bits {
+        println!("cargo:rerun-if-changed={}", package.manifest_path);
+    }
+    println!("cargo:rerun-if-changed=Cargo.toml");
+}
+
+fn result_variant(kind: &str) -> syn::Ident {
+    format_ident!("{}", heck::AsUpperCamelCase(kind).to_string())
+}
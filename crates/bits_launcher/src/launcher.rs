@@ -0,0 +1,174 @@
+use std::env;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, Command, ExitCode, Stdio};
+
+use bits_helpers::BIT_RESULT_PREFIX;
+
+use crate::registry::{BITS, BitInfo, find_bit};
+
+const USAGE: &str = "\
+Usage:
+  bits_launcher                       pick bits from a menu
+  bits_launcher <bit>                 launch a bit, by name or number
+  bits_launcher --playlist <bit>...   launch bits back to back, like the platform does
+";
+
+/// Runs a bit in this process, the launcher starting each bit in a child process
+/// since an event loop can only be created once per process
+const RUN_FLAG: &str = "--run";
+
+pub fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        [] => menu(),
+        [RUN_FLAG, name] => find_bit(name).map_or(ExitCode::FAILURE, |bit| {
+            (bit.run)();
+            ExitCode::SUCCESS
+        }),
+        ["--playlist", names @ ..] if !names.is_empty() => {
+            find_bits(names).map_or(ExitCode::FAILURE, |bits| play(&bits))
+        }
+        [name] if !name.starts_with('-') => {
+            find_bits(&[name]).map_or(ExitCode::FAILURE, |bits| play(&bits))
+        }
+        _ => {
+            eprint!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn find_bits(selections: &[&str]) -> Option<Vec<&'static BitInfo>> {
+    selections
+        .iter()
+        .map(|selection| {
+            let bit = find_bit(selection);
+            if bit.is_none() {
+                eprintln!(
+                    "Unknown bit {selection}, run bits_launcher without arguments to list them"
+                );
+            }
+            bit
+        })
+        .collect()
+}
+
+fn menu() -> ExitCode {
+    let mut line = String::new();
+    loop {
+        print_bits();
+        println!();
+        println!("Enter a bit to launch it, several for a playlist, `all` for every bit");
+        print!("or nothing to quit: ");
+
+        line.clear();
+        if let Err(error) = io::stdout()
+            .flush()
+            .and_then(|()| io::stdin().lock().read_line(&mut line))
+        {
+            eprintln!("Could not read the selection: {error}");
+            return ExitCode::FAILURE;
+        }
+
+        let selections: Vec<&str> = line.split_whitespace().collect();
+        let bits = match selections.as_slice() {
+            [] => return ExitCode::SUCCESS,
+            ["all"] => Some(BITS.iter().collect()),
+            selections => find_bits(selections),
+        };
+
+        if let Some(bits) = bits {
+            play(&bits);
+        }
+    }
+}
+
+fn print_bits() {
+    println!("Bits:");
+    for (number, bit) in BITS.iter().enumerate() {
+        let duration = bit.duration.map_or_else(
+            || "platform max".to_string(),
+            |duration| format!("{}s", duration.as_secs()),
+        );
+        println!(
+            "{:>3}. {:<16} {:<7} {} ({}, {duration})",
+            number + 1,
+            bit.name,
+            bit.version,
+            bit.description,
+            bit.result.label(),
+        );
+    }
+}
+
+/// Launches bits one after the other, each ending as soon as it reports a result
+fn play(bits: &[&BitInfo]) -> ExitCode {
+    if let [bit] = bits {
+        return match launch(bit, false) {
+            Ok(_) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("Could not launch {}: {error}", bit.name);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut results = Vec::new();
+    for (played, bit) in bits.iter().enumerate() {
+        println!("Playing {} ({}/{})", bit.name, played + 1, bits.len());
+        let result = launch(bit, true).unwrap_or_else(|error| {
+            eprintln!("Could not launch {}: {error}", bit.name);
+            None
+        });
+        results.push((bit.name, result));
+    }
+
+    println!("Playlist results:");
+    for (name, result) in results {
+        let result = result.unwrap_or_else(|| "closed before the end".to_string());
+        println!("  {name:<16} {result}");
+    }
+    ExitCode::SUCCESS
+}
+
+/// Runs a bit in a child process until its window closes, returning the result it printed
+fn launch(bit: &BitInfo, exit_on_end: bool) -> io::Result<Option<String>> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .args([RUN_FLAG, bit.name])
+        // Assets are looked up next to the launcher's manifest otherwise
+        .env("BEVY_ASSET_ROOT", bit.manifest_dir)
+        .stdout(Stdio::piped());
+    if exit_on_end {
+        command.env("BITS_EXIT_ON_END", "1");
+    }
+
+    let mut child = command.spawn()?;
+    let result = forward_output(&mut child);
+    if result.is_err() {
+        // The bit is not left running unattended, it may have exited already
+        if let Err(error) = child.kill() {
+            eprintln!("Could not stop {}: {error}", bit.name);
+        }
+    }
+    child.wait()?;
+
+    result
+}
+
+/// Prints the output of a bit, returning the last result it reported
+fn forward_output(child: &mut Child) -> io::Result<Option<String>> {
+    let mut result = None;
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines() {
+            let line = line?;
+            match line.strip_prefix(BIT_RESULT_PREFIX) {
+                Some(bit_result) => result = Some(bit_result.to_string()),
+                None => println!("{line}"),
+            }
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,12 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod launcher;
+#[cfg(not(target_arch = "wasm32"))]
+mod registry;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> std::process::ExitCode {
+    launcher::main()
+}
+
+#[cfg(target_arch = "wasm32")]
+const fn main() {}
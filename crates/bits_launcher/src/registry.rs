@@ -0,0 +1,37 @@
+use core::time::Duration;
+
+/// A bit of the workspace, declared by a `[package.metadata.bit]` table in its manifest:
+///
+/// ```toml
+/// [package]
+/// description = "Catch the target emoji among the falling ones"
+///
+/// [package.metadata.bit]
+/// result = "highest_score"
+/// duration = "max"
+/// ```
+///
+/// `duration` is a number of seconds, or `"max"` for the longest duration the platform allows.
+pub struct BitInfo {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub description: &'static str,
+    pub result: ResultKind,
+    /// `None` for the longest duration the platform allows
+    pub duration: Option<Duration>,
+    /// Where the bit's `assets` folder is
+    pub manifest_dir: &'static str,
+    pub run: fn(),
+}
+
+// Declares `ResultKind`, with a variant for each `result` the bits report, and `BITS`
+include!(concat!(env!("OUT_DIR"), "/bits.rs"));
+
+/// Finds a bit by name, or by its 1-based number in [`BITS`]
+pub fn find_bit(selection: &str) -> Option<&'static BitInfo> {
+    selection
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| BITS.get(number.checked_sub(1)?))
+        .or_else(|| BITS.iter().find(|bit| bit.name == selection))
+}
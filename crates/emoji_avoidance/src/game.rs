@@ -1,11 +1,11 @@
-use std::time::Duration;
-
 use bevy::prelude::*;
 use bits_helpers::FONT;
+use bits_helpers::i18n::Strings;
+use bits_helpers::play_clock::{PlayClock, PlayClockPlugin};
 use bits_helpers::welcome_screen::{WelcomeScreenElement, despawn_welcome_screen};
 
-pub const WINDOW_WIDTH: f32 = 800.0;
-pub const WINDOW_HEIGHT: f32 = 600.0;
+// The virtual resolution shared by every bit
+pub use bits_helpers::{WINDOW_HEIGHT, WINDOW_WIDTH};
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
 pub enum GameState {
@@ -18,23 +18,12 @@ pub enum GameState {
 #[derive(Component)]
 pub struct TimerText;
 
-#[derive(Resource, Default)]
-pub struct GameTimer(pub f32);
-
-impl From<&GameTimer> for Duration {
-    fn from(value: &GameTimer) -> Self {
-        let secs = value.0.trunc() as u64;
-        let nanos = (value.0.fract() * 1e9) as u32;
-        Self::new(secs, nanos)
-    }
-}
-
 pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
-            .init_resource::<GameTimer>()
+            .add_plugins(PlayClockPlugin::new().in_state(GameState::Playing))
             .add_systems(OnEnter(GameState::Welcome), spawn_welcome_screen)
             .add_systems(OnExit(GameState::Welcome), despawn_welcome_screen)
             .add_systems(OnEnter(GameState::Playing), init_timer)
@@ -48,11 +37,11 @@ impl Plugin for GamePlugin {
     }
 }
 
-fn init_timer(mut game_timer: ResMut<GameTimer>) {
-    game_timer.0 = 0.0;
+fn init_timer(mut play_clock: ResMut<PlayClock>) {
+    play_clock.reset();
 }
 
-fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>, strings: Strings) {
     let font: Handle<Font> = asset_server.load(FONT);
     commands
         .spawn((
@@ -78,7 +67,7 @@ fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                 TextColor(Color::WHITE),
             ));
             parent.spawn((
-                Text::new("Avoid the falling emojis!"),
+                Text::new(strings.get("emoji_avoidance.instructions")),
                 TextFont {
                     font: font.clone(),
                     font_size: 20.0,
@@ -87,7 +76,7 @@ fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                 TextColor(Color::WHITE),
             ));
             parent.spawn((
-                Text::new("Tap to start"),
+                Text::new(strings.get("welcome.tap_to_start")),
                 TextFont {
                     font,
                     font_size: 20.0,
@@ -109,13 +98,14 @@ fn handle_welcome_input(
 }
 
 fn update_timer(
-    mut game_timer: ResMut<GameTimer>,
+    play_clock: Res<PlayClock>,
+    strings: Strings,
     mut query: Query<&mut Text, With<TimerText>>,
-    time: Res<Time>,
 ) {
-    game_timer.0 += time.delta_secs();
-
     if let Ok(mut text) = query.get_single_mut() {
-        text.0 = format!("Time: {:.1}", game_timer.0);
+        text.0 = strings.format(
+            "emoji_avoidance.time",
+            &[("time", play_clock.elapsed().as_secs_f32().into())],
+        );
     }
 }
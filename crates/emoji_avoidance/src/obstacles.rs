@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use bits_helpers::emoji::{self, AtlasValidation, EMOJI_SIZE, EmojiAtlas};
+use bits_helpers::play_clock::PlayClock;
 
-use crate::game::{GameState, GameTimer, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::game::{GameState, WINDOW_HEIGHT, WINDOW_WIDTH};
 use crate::player::PLAYER_WIDTH;
 
 /// Minimum and maximum scale factors for emoji obstacles
@@ -128,11 +129,12 @@ fn spawn_obstacles(
     atlas: Res<EmojiAtlas>,
     validation: Res<AtlasValidation>,
     mut spawn_timer: ResMut<SpawnTimer>,
-    game_timer: Res<GameTimer>,
+    play_clock: Res<PlayClock>,
     time: Res<Time>,
     obstacle_query: Query<(&Transform, &Obstacle)>,
 ) {
     spawn_timer.0.tick(time.delta());
+    let game_time = play_clock.elapsed().as_secs_f32();
 
     if spawn_timer.0.just_finished() {
         let mut attempts = 0;
@@ -150,7 +152,7 @@ fn spawn_obstacles(
             if !would_block_all_paths(start_pos, size, &obstacle_query, WINDOW_WIDTH) {
                 let available_emojis = emoji::get_random_emojis(&atlas, &validation, 1);
                 if let Some(&emoji_index) = available_emojis.first() {
-                    let speed = calculate_speed(game_timer.0);
+                    let speed = calculate_speed(game_time);
 
                     let rotation_speed = if fastrand::f32() < 0.75 {
                         fastrand::f32()
@@ -183,8 +185,7 @@ fn spawn_obstacles(
             attempts += 1;
         }
 
-        let new_spawn_rate = game_timer
-            .0
+        let new_spawn_rate = game_time
             .mul_add(-0.02, INITIAL_OBSTACLE_SPAWN_RATE)
             .max(MIN_SPAWN_INTERVAL);
         spawn_timer.0 = Timer::from_seconds(new_spawn_rate, TimerMode::Repeating);
@@ -1,12 +1,12 @@
-use std::time::Duration;
-
 use bevy::prelude::*;
 use bits_helpers::emoji::{self, AtlasValidation, EmojiAtlas};
+use bits_helpers::i18n::Strings;
 use bits_helpers::input::{just_pressed_world_position, pressed_world_position};
+use bits_helpers::play_clock::PlayClock;
 use bits_helpers::{FONT, send_bit_message};
-use ribbit_bits::{BitMessage, BitResult};
+use ribbit_bits::BitMessage;
 
-use crate::game::{GameState, GameTimer, TimerText, WINDOW_HEIGHT};
+use crate::game::{GameState, TimerText, WINDOW_HEIGHT};
 use crate::obstacles::{Obstacle, check_collision};
 
 pub const PLAYER_WIDTH: f32 = 40.0;
@@ -44,6 +44,7 @@ fn spawn_player(
     atlas: Res<EmojiAtlas>,
     validation: Res<AtlasValidation>,
     asset_server: Res<AssetServer>,
+    strings: Strings,
 ) {
     let player_radius = PLAYER_WIDTH.min(PLAYER_HEIGHT) / 2.0;
 
@@ -67,7 +68,7 @@ fn spawn_player(
 
     // Spawn timer text
     commands.spawn((
-        Text::new("Time: 0.0"),
+        Text::new(strings.format("emoji_avoidance.time", &[("time", 0.0_f32.into())])),
         TextFont {
             font: asset_server.load(FONT),
             font_size: 20.0,
@@ -155,7 +156,7 @@ fn check_collisions(
     player_query: Query<(&Transform, &Player)>,
     obstacle_query: Query<(&Transform, &Obstacle)>,
     mut next_state: ResMut<NextState<GameState>>,
-    game_timer: ResMut<GameTimer>,
+    play_clock: Res<PlayClock>,
 ) {
     let (player_transform, player) = player_query.single();
     let player_pos = player_transform.translation.truncate();
@@ -165,9 +166,7 @@ fn check_collisions(
 
         if check_collision(player_pos, player.radius, obstacle_pos, obstacle.radius) {
             next_state.set(GameState::GameOver);
-            send_bit_message(BitMessage::End(BitResult::LongestDuration(
-                Duration::from_secs_f32(game_timer.0),
-            )));
+            send_bit_message(BitMessage::End(play_clock.longest_result()));
             return;
         }
     }
@@ -1,6 +1,7 @@
 use bevy::log::info;
 use bevy::state::state::NextState;
 use bits_helpers::RibbitMessageHandler;
+use bits_helpers::play_clock::PlayClock;
 use ribbit_bits::{BitDuration, BitResult};
 
 use crate::game::GameState;
@@ -20,7 +21,7 @@ impl RibbitMessageHandler for EmojiAvoidance {
         let mut next_state = world.resource_mut::<NextState<GameState>>();
         next_state.set(GameState::GameOver);
         info!("Ending EmojiAvoidance");
-        BitResult::Failure
+        world.resource::<PlayClock>().longest_result()
     }
 
     fn duration(_world: &mut bevy::prelude::World) -> BitDuration {
@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::Difficulty;
+use bits_helpers::score::{ResultPolicy, ScoreEvent, ScoreKeeper};
 
 pub struct GamePlugin;
 
@@ -19,7 +21,6 @@ pub struct ChainEvent(pub u32);
 
 #[derive(Resource)]
 pub struct GameProgress {
-    pub score: u32,
     pub moves_remaining: u32,
     pub level: u32,
 }
@@ -27,7 +28,6 @@ pub struct GameProgress {
 impl Default for GameProgress {
     fn default() -> Self {
         Self {
-            score: 0,
             moves_remaining: INITIAL_MOVES,
             level: 1,
         }
@@ -85,6 +85,7 @@ impl Default for LevelConfig {
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GameProgress>()
+            .insert_resource(ScoreKeeper::new(ResultPolicy::HighestScore))
             .init_resource::<LevelConfig>()
             .add_event::<ChainEvent>()
             .add_systems(
@@ -101,16 +102,23 @@ fn handle_game_over(progress: Res<GameProgress>, mut next_state: ResMut<NextStat
     }
 }
 
-fn handle_scoring(mut progress: ResMut<GameProgress>, mut chain_events: EventReader<ChainEvent>) {
+fn handle_scoring(
+    mut progress: ResMut<GameProgress>,
+    mut score: ResMut<ScoreKeeper>,
+    mut chain_events: EventReader<ChainEvent>,
+) {
     for ChainEvent(chain_count) in chain_events.read() {
-        let base_score = BASE_MATCH_SCORE * chain_count;
-        let chain_bonus = CHAIN_MULTIPLIER * (chain_count - 1);
-        progress.score += base_score + chain_bonus;
+        score.record(ScoreEvent::Points(BASE_MATCH_SCORE * chain_count));
+        score.record(ScoreEvent::Bonus(CHAIN_MULTIPLIER * (chain_count - 1)));
         progress.moves_remaining -= 1;
     }
 }
 
-fn update_difficulty(mut config: ResMut<LevelConfig>, progress: Res<GameProgress>) {
+fn update_difficulty(
+    mut config: ResMut<LevelConfig>,
+    progress: Res<GameProgress>,
+    difficulty: Difficulty,
+) {
     // Adjust difficulty based on level
-    config.num_emoji_types = (6 + (progress.level / 3)).min(10) as usize;
+    config.num_emoji_types = difficulty.sample("emoji_types", progress.level as f32) as usize;
 }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::DifficultyPlugin;
 use bits_helpers::emoji::EmojiPlugin;
 
 mod game;
@@ -16,6 +17,10 @@ pub fn run() {
         env!("CARGO_PKG_VERSION"),
     )
     .add_plugins(EmojiPlugin)
+    .add_plugins(DifficultyPlugin::new(
+        "levels.difficulty.ron",
+        include_str!("../assets/levels.difficulty.ron"),
+    ))
     .add_plugins(GamePlugin)
     .add_plugins(GridPlugin)
     .add_plugins(ScreenPlugin)
@@ -1,5 +1,6 @@
 use bevy::log::info;
 use bits_helpers::RibbitMessageHandler;
+use bits_helpers::score::ScoreKeeper;
 use ribbit_bits::{BitDuration, BitResult};
 
 #[derive(Default, Clone, Copy)]
@@ -10,9 +11,9 @@ impl RibbitMessageHandler for EmojiCascade {
         info!("Restarting EmojiCascade");
     }
 
-    fn end(_world: &mut bevy::prelude::World) -> BitResult {
+    fn end(world: &mut bevy::prelude::World) -> BitResult {
         info!("Ending EmojiCascade");
-        BitResult::Failure
+        world.resource::<ScoreKeeper>().result()
     }
 
     fn duration(_world: &mut bevy::prelude::World) -> BitDuration {
@@ -1,6 +1,8 @@
 use bevy::color::palettes::css::{GREEN, YELLOW};
 use bevy::prelude::*;
+use bits_helpers::i18n::Strings;
 use bits_helpers::input::just_pressed_world_position;
+use bits_helpers::score::ScoreKeeper;
 use bits_helpers::FONT;
 
 use crate::game::{GameProgress, GameState};
@@ -46,7 +48,7 @@ impl Plugin for ScreenPlugin {
     }
 }
 
-fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>, strings: Strings) {
     let font = asset_server.load(FONT);
     let base_text_font = TextFont {
         font,
@@ -67,7 +69,7 @@ fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>)
 
             // Instructions
             parent.spawn((
-                Text2d::new("Match 3 or more emojis\nby sliding rows and columns"),
+                Text2d::new(strings.get("emoji_cascade.instructions")),
                 base_text_font.clone(),
                 TextLayout::new_with_justify(JustifyText::Center),
                 Transform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
@@ -75,7 +77,7 @@ fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>)
 
             // Start prompt
             parent.spawn((
-                Text2d::new("Click or tap to start"),
+                Text2d::new(strings.get("emoji_cascade.start")),
                 base_text_font.with_font_size(40.0),
                 TextLayout::new_with_justify(JustifyText::Center),
                 TextColor(Color::Srgba(YELLOW)),
@@ -87,7 +89,9 @@ fn spawn_welcome_screen(mut commands: Commands, asset_server: Res<AssetServer>)
 fn spawn_game_over(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    strings: Strings,
     progress: Res<GameProgress>,
+    score: Res<ScoreKeeper>,
 ) {
     let font = asset_server.load(FONT);
     let base_text_font = TextFont {
@@ -101,7 +105,7 @@ fn spawn_game_over(
         .with_children(|parent| {
             // Game Over text
             parent.spawn((
-                Text2d::new("Game Over!"),
+                Text2d::new(strings.get("game_over.title")),
                 base_text_font.clone().with_font_size(64.0),
                 TextLayout::new_with_justify(JustifyText::Center),
                 Transform::from_translation(Vec3::new(0.0, 100.0, 0.0)),
@@ -109,7 +113,9 @@ fn spawn_game_over(
 
             // Final Score
             parent.spawn((
-                Text2d::new(format!("Final Score: {}", progress.score)),
+                Text2d::new(
+                    strings.format("game_over.final_score", &[("score", score.total().into())]),
+                ),
                 base_text_font.clone(),
                 TextLayout::new_with_justify(JustifyText::Center),
                 TextColor(Color::Srgba(YELLOW)),
@@ -118,7 +124,9 @@ fn spawn_game_over(
 
             // Level Reached
             parent.spawn((
-                Text2d::new(format!("Level: {}", progress.level)),
+                Text2d::new(
+                    strings.format("emoji_cascade.level", &[("level", progress.level.into())]),
+                ),
                 base_text_font.clone().with_font_size(40.0),
                 TextLayout::new_with_justify(JustifyText::Center),
                 Transform::from_translation(Vec3::new(0.0, -50.0, 0.0)),
@@ -126,7 +134,7 @@ fn spawn_game_over(
 
             // Restart prompt
             parent.spawn((
-                Text2d::new("Click or tap to play again"),
+                Text2d::new(strings.get("emoji_cascade.play_again")),
                 base_text_font.with_font_size(40.0),
                 TextLayout::new_with_justify(JustifyText::Center),
                 TextColor(Color::Srgba(GREEN)),
@@ -135,7 +143,13 @@ fn spawn_game_over(
         });
 }
 
-fn spawn_score_display(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn spawn_score_display(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    strings: Strings,
+    progress: Res<GameProgress>,
+    score: Res<ScoreKeeper>,
+) {
     let font = asset_server.load(FONT);
     let score_text_font = TextFont {
         font,
@@ -151,7 +165,7 @@ fn spawn_score_display(mut commands: Commands, asset_server: Res<AssetServer>) {
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text2d::new("Score: 0\nMoves: 15\nLevel: 1"),
+                Text2d::new(score_text(&strings, &progress, &score)),
                 score_text_font,
                 TextLayout::new(JustifyText::Left, LineBreak::WordBoundary),
             ));
@@ -176,26 +190,38 @@ fn handle_game_over_input(
     touch_input: Res<Touches>,
     camera_q: Query<(&Camera, &GlobalTransform)>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut score: ResMut<ScoreKeeper>,
     mut commands: Commands,
 ) {
     if just_pressed_world_position(&buttons, &touch_input, &windows, &camera_q).is_some() {
         commands.insert_resource(GameProgress::default());
+        score.reset();
         next_state.set(GameState::Welcome);
     }
 }
 
 fn update_score_display(
     mut query: Query<&mut Text2d, With<ScoreDisplay>>,
+    strings: Strings,
     progress: Res<GameProgress>,
+    score: Res<ScoreKeeper>,
 ) {
     if let Ok(mut text) = query.get_single_mut() {
-        text.0 = format!(
-            "Score: {}\nMoves: {}\nLevel: {}",
-            progress.score, progress.moves_remaining, progress.level
-        );
+        text.0 = score_text(&strings, &progress, &score);
     }
 }
 
+fn score_text(strings: &Strings, progress: &GameProgress, score: &ScoreKeeper) -> String {
+    strings.format(
+        "emoji_cascade.status",
+        &[
+            ("score", score.total().into()),
+            ("moves", progress.moves_remaining.into()),
+            ("level", progress.level.into()),
+        ],
+    )
+}
+
 fn despawn_screen<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
     for entity in &query {
         commands.entity(entity).despawn_recursive();
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 
 /// Game states that control the flow of the application
@@ -42,6 +44,8 @@ pub struct SpawnTimer {
     pub current_speed: f32,
     /// Current spawn rate
     pub spawn_rate: f32,
+    /// Time played, which drives the difficulty
+    pub elapsed: Duration,
 }
 
 impl Default for SpawnTimer {
@@ -50,6 +54,7 @@ impl Default for SpawnTimer {
             timer: Timer::from_seconds(1.0, TimerMode::Repeating),
             current_speed: 100.0,
             spawn_rate: 1.0,
+            elapsed: Duration::ZERO,
         }
     }
 }
@@ -89,15 +94,12 @@ pub mod config {
     // Emoji configuration
     pub const MIN_EMOJI_SIZE: f32 = 30.0;
     pub const MAX_EMOJI_SIZE: f32 = 60.0;
-    pub const MAX_FALL_SPEED: f32 = 400.0;
 
     // Rotation configuration
     pub const MIN_ROTATION_SPEED: f32 = 1.0; // Radians per second
     pub const MAX_ROTATION_SPEED: f32 = 5.0; // Radians per second
     pub const ROTATION_CHANCE: f32 = 0.6; // Chance for an emoji to rotate
 
-    // Difficulty scaling
-    pub const SPEED_INCREASE_RATE: f32 = 10.0; // Speed increase per second
-    pub const MIN_SPAWN_INTERVAL: f32 = 0.5; // Minimum time between spawns
-    pub const SPAWN_RATE_DECREASE: f32 = 0.05; // How much spawn interval decreases per second
+    // Scoring configuration
+    pub const MISSED_TARGET_PENALTY: i32 = 5;
 }
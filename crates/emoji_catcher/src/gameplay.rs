@@ -1,11 +1,12 @@
 use bevy::prelude::*;
+use bits_helpers::debug::DebugCollider;
+use bits_helpers::difficulty::Difficulty;
 use bits_helpers::floating_score::{FloatingScore, spawn_floating_score};
 use bits_helpers::input::pressed_world_position;
 use bits_helpers::{WINDOW_HEIGHT, WINDOW_WIDTH, emoji, send_bit_message};
 use config::{
-    CATCHER_SIZE, MAX_EMOJI_SIZE, MAX_FALL_SPEED, MAX_ROTATION_SPEED, MIN_EMOJI_SIZE,
-    MIN_ROTATION_SPEED, MIN_SPAWN_INTERVAL, ROTATION_CHANCE, SPAWN_RATE_DECREASE,
-    SPEED_INCREASE_RATE,
+    CATCHER_SIZE, MAX_EMOJI_SIZE, MAX_ROTATION_SPEED, MIN_EMOJI_SIZE, MIN_ROTATION_SPEED,
+    ROTATION_CHANCE,
 };
 use ribbit_bits::BitMessage;
 
@@ -21,19 +22,6 @@ pub struct GameOverDelay {
     pub timer: Timer,
 }
 
-/// Component to mark entities used for collision debug visualization
-#[derive(Component)]
-pub struct CollisionDebug;
-
-/// Component for rendering circles in 2D
-#[derive(Component, Default)]
-pub struct Circle {
-    /// Radius of the circle
-    pub radius: f32,
-    /// Color of the circle
-    pub color: Color,
-}
-
 /// Spawns initial game elements including the catcher and UI
 pub fn spawn_game_elements(mut commands: Commands, asset_server: Res<AssetServer>) {
     // Spawn catcher at bottom of screen using custom sprite
@@ -55,6 +43,7 @@ pub fn spawn_game_elements(mut commands: Commands, asset_server: Res<AssetServer
         Catcher {
             width: CATCHER_SIZE.x,
         },
+        DebugCollider::Circle(CATCHER_SIZE.x * config::COLLISION_CIRCLE_PERCENT / 2.0),
     ));
 
     // Define a safe margin from the edges of the window
@@ -183,17 +172,15 @@ pub fn update_game(
     score: Res<Score>,
     target_emoji: Res<TargetEmojiIndex>,
     game_timer: Res<GameTimer>,
+    difficulty: Difficulty,
 ) {
     // Update spawn timer
     spawn_timer.timer.tick(time.delta());
 
     // Increase difficulty
-    spawn_timer.current_speed = SPEED_INCREASE_RATE
-        .mul_add(time.delta_secs(), spawn_timer.current_speed)
-        .min(MAX_FALL_SPEED);
-    spawn_timer.spawn_rate = SPAWN_RATE_DECREASE
-        .mul_add(-time.delta_secs(), spawn_timer.spawn_rate)
-        .max(MIN_SPAWN_INTERVAL);
+    spawn_timer.elapsed += time.delta();
+    spawn_timer.current_speed = difficulty.at_time("fall_speed", spawn_timer.elapsed);
+    spawn_timer.spawn_rate = difficulty.at_time("spawn_interval", spawn_timer.elapsed);
 
     // Update score display
     if let Some(mut score_text) = score_query.iter_mut().next() {
@@ -260,12 +247,15 @@ pub fn update_game(
             emoji_index,
             emoji_transform,
         ) {
-            commands.entity(emoji_entity).insert(FallingEmoji {
-                speed: spawn_timer.current_speed,
-                is_target,
-                size,
-                rotation_speed,
-            });
+            commands.entity(emoji_entity).insert((
+                FallingEmoji {
+                    speed: spawn_timer.current_speed,
+                    is_target,
+                    size,
+                    rotation_speed,
+                },
+                DebugCollider::Circle(size * config::COLLISION_CIRCLE_PERCENT / 2.0),
+            ));
         }
 
         // Reset timer with new spawn rate
@@ -280,13 +270,9 @@ pub fn move_emojis(
     mut score: ResMut<Score>,
     mut emoji_query: Query<(Entity, &mut Transform, &FallingEmoji)>,
     catcher_query: Query<(&Transform, &Catcher), Without<FallingEmoji>>,
-    collision_debug_query: Query<Entity, With<CollisionDebug>>,
     asset_server: Res<AssetServer>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    // Debug visualization of collision circles
-    const DEBUG_COLLISION: bool = false;
-
     let Ok((catcher_transform, catcher)) = catcher_query.get_single() else {
         return;
     };
@@ -297,25 +283,6 @@ pub fn move_emojis(
     let catcher_radius = (catcher.width * config::COLLISION_CIRCLE_PERCENT) / 2.0;
     let catcher_pos = catcher_transform.translation.truncate();
 
-    // Debug visualization for catcher collision circle
-    if DEBUG_COLLISION {
-        // First, remove any existing debug circles
-        for debug_entity in collision_debug_query.iter() {
-            commands.entity(debug_entity).despawn();
-        }
-
-        // Create catcher collision circle visualization using a simple sprite circle
-        commands.spawn((
-            Circle {
-                radius: catcher_radius,
-                color: Color::srgba(1.0, 0.0, 0.0, 0.3),
-            },
-            Transform::from_xyz(catcher_pos.x, catcher_pos.y, 1.0),
-            Visibility::Visible,
-            CollisionDebug,
-        ));
-    }
-
     for (emoji_entity, mut transform, emoji) in &mut emoji_query {
         // Move emoji down
         transform.translation.y -= emoji.speed * time.delta_secs();
@@ -329,20 +296,6 @@ pub fn move_emojis(
         let emoji_radius = (emoji.size * config::COLLISION_CIRCLE_PERCENT) / 2.0;
         let emoji_pos = transform.translation.truncate();
 
-        // Debug visualization for emoji collision circle
-        if DEBUG_COLLISION {
-            // Create emoji collision circle visualization using a simple sprite circle
-            commands.spawn((
-                Circle {
-                    radius: emoji_radius,
-                    color: Color::srgba(0.0, 1.0, 0.0, 0.3),
-                },
-                Transform::from_xyz(emoji_pos.x, emoji_pos.y, 1.0),
-                Visibility::Visible,
-                CollisionDebug,
-            ));
-        }
-
         // Circle-Circle collision test
         let distance_squared = (emoji_pos - catcher_pos).length_squared();
         let combined_radius = catcher_radius + emoji_radius;
@@ -380,7 +333,7 @@ pub fn move_emojis(
 
             if emoji.is_target {
                 // Missed target emoji - game over!
-                score.0 -= 5;
+                score.0 -= config::MISSED_TARGET_PENALTY;
                 spawn_floating_score(
                     &mut commands,
                     Vec2::new(transform.translation.x, -WINDOW_HEIGHT / 2.0),
@@ -413,7 +366,6 @@ pub fn cleanup_game(
             With<Text2d>,
             With<FloatingScore>,
             With<GameOverDelay>,
-            With<CollisionDebug>,
         )>,
     >,
 ) {
@@ -421,16 +373,3 @@ pub fn cleanup_game(
         commands.entity(entity).despawn_recursive();
     }
 }
-
-/// Renders debug circles
-pub fn render_circles(circles: Query<(&Circle, &Transform, &Visibility)>, mut gizmos: Gizmos) {
-    for (circle, transform, visibility) in &circles {
-        if *visibility == Visibility::Visible {
-            gizmos.circle_2d(
-                transform.translation.truncate(),
-                circle.radius,
-                circle.color,
-            );
-        }
-    }
-}
@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use bits_helpers::debug::DebugAppExt;
+use bits_helpers::difficulty::DifficultyPlugin;
 use bits_helpers::emoji::{self, AtlasValidation, EmojiPlugin};
 use bits_helpers::floating_score::animate_floating_scores;
 use ribbit::EmojiCatcher;
@@ -11,8 +13,7 @@ mod welcome;
 use core::{GameState, GameTimer, Score, TargetEmojiIndex};
 
 use gameplay::{
-    cleanup_game, handle_input, move_emojis, render_circles, spawn_game_elements, update_game,
-    update_game_timer,
+    cleanup_game, handle_input, move_emojis, spawn_game_elements, update_game, update_game_timer,
 };
 use welcome::{
     add_emoji_to_welcome_screen, despawn_welcome_screen, handle_welcome_input, spawn_welcome_screen,
@@ -26,8 +27,14 @@ pub fn run() {
     );
 
     app.add_plugins(EmojiPlugin)
+        .add_plugins(DifficultyPlugin::new(
+            "falling.difficulty.ron",
+            include_str!("../assets/falling.difficulty.ron"),
+        ))
         // Initialize core resources
         .init_state::<GameState>()
+        .debug_state::<GameState>()
+        .debug_commands::<EmojiCatcher>()
         .init_resource::<GameTimer>()
         .init_resource::<Score>()
         .init_resource::<TargetEmojiIndex>()
@@ -57,7 +64,6 @@ pub fn run() {
                 update_game,
                 update_game_timer,
                 animate_floating_scores,
-                render_circles,
             )
                 .run_if(in_state(GameState::Playing))
                 .run_if(emoji_system_ready),
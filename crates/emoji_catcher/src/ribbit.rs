@@ -1,9 +1,12 @@
+use core::time::Duration;
+
 use bevy::log::info;
-use bevy::prelude::NextState;
-use bits_helpers::RibbitMessageHandler;
-use ribbit_bits::{BitDuration, BitResult};
+use bevy::prelude::{NextState, State, World};
+use bits_helpers::debug::{DebugCommandError, DebugCommands};
+use bits_helpers::{RibbitMessageHandler, send_bit_message};
+use ribbit_bits::{BitDuration, BitMessage, BitResult};
 
-use crate::core::{GameState, Score};
+use crate::core::{GameState, GameTimer, Score, config};
 
 #[derive(Default, Clone, Copy)]
 pub struct EmojiCatcher;
@@ -39,3 +42,57 @@ impl RibbitMessageHandler for EmojiCatcher {
         BitDuration::max_duration()
     }
 }
+
+impl DebugCommands for EmojiCatcher {
+    fn set_score(world: &mut World, score: i64) -> Result<(), DebugCommandError> {
+        let score = i32::try_from(score)
+            .map_err(|error| DebugCommandError::Rejected(format!("{score}: {error}")))?;
+        world.resource_mut::<Score>().0 = score;
+        Ok(())
+    }
+
+    /// Moves the game clock, e.g. `time 15` to reach the rotation mode
+    fn add_time(world: &mut World, seconds: f32) -> Result<(), DebugCommandError> {
+        ensure_playing(world)?;
+
+        let mut game_timer = world.resource_mut::<GameTimer>();
+        let elapsed =
+            Duration::try_from_secs_f32((game_timer.timer.elapsed_secs() + seconds).max(0.0))
+                .map_err(|error| DebugCommandError::Rejected(format!("{seconds}: {error}")))?;
+        game_timer.timer.set_elapsed(elapsed);
+        Ok(())
+    }
+
+    /// Ends the game with the current score, as if the player stopped while ahead
+    fn win(world: &mut World) -> Result<(), DebugCommandError> {
+        ensure_playing(world)?;
+        end_game(world);
+        Ok(())
+    }
+
+    /// Ends the game as if the target emoji was missed
+    fn lose(world: &mut World) -> Result<(), DebugCommandError> {
+        ensure_playing(world)?;
+        world.resource_mut::<Score>().0 -= config::MISSED_TARGET_PENALTY;
+        end_game(world);
+        Ok(())
+    }
+}
+
+fn ensure_playing(world: &World) -> Result<(), DebugCommandError> {
+    if *world.resource::<State<GameState>>().get() == GameState::Playing {
+        Ok(())
+    } else {
+        Err(DebugCommandError::Rejected(
+            "The game is not started".to_string(),
+        ))
+    }
+}
+
+fn end_game(world: &mut World) {
+    world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::GameOver);
+    let score = world.resource::<Score>().0;
+    send_bit_message(BitMessage::End(BitResult::HighestScore(score.into())));
+}
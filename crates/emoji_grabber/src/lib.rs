@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::DifficultyPlugin;
 use bits_helpers::emoji::EmojiPlugin;
 use bits_helpers::floating_score::animate_floating_scores;
 use gameplay::reset_score;
@@ -33,6 +34,10 @@ pub fn run() {
     );
 
     app.add_plugins(EmojiPlugin)
+        .add_plugins(DifficultyPlugin::new(
+            "stages.difficulty.ron",
+            include_str!("../assets/stages.difficulty.ron"),
+        ))
         // Initialize core resources
         .init_state::<GameState>()
         .init_resource::<GameTimer>()
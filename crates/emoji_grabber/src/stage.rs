@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::Difficulty;
+use bits_helpers::i18n::Strings;
 use bits_helpers::{FONT, emoji};
 
 use crate::core::{CorrectEmojisFound, GameState, GameTimer, Score, StageConfig, TargetEmojiInfo};
@@ -11,19 +13,28 @@ pub struct StageCompleteScreen;
 pub fn spawn_stage_complete_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    strings: Strings,
     score: Res<Score>,
     stage_config: Res<StageConfig>,
     correct_emojis_found: Res<CorrectEmojisFound>,
 ) {
     let completion_text = if correct_emojis_found.0 >= stage_config.stage.correct_emojis {
-        format!(
-            "Stage {} Complete!\n\nYou found all {}\nTotal Score: {}\n\nClick to continue",
-            stage_config.current_stage_number, stage_config.stage.correct_emojis, score.0
+        strings.format(
+            "emoji_grabber.stage_complete",
+            &[
+                ("stage", stage_config.current_stage_number.into()),
+                ("total", stage_config.stage.correct_emojis.into()),
+                ("score", score.0.into()),
+            ],
         )
     } else {
-        format!(
-            "Time's Up!\n\nYou found {} of {}\nTotal Score: {}\n\nClick to continue",
-            correct_emojis_found.0, stage_config.stage.correct_emojis, score.0
+        strings.format(
+            "emoji_grabber.times_up",
+            &[
+                ("found", correct_emojis_found.0.into()),
+                ("total", stage_config.stage.correct_emojis.into()),
+                ("score", score.0.into()),
+            ],
         )
     };
 
@@ -46,6 +57,7 @@ pub fn handle_stage_complete_input(
     touch_input: Res<Touches>,
     mut next_state: ResMut<NextState<GameState>>,
     mut stage_config: ResMut<StageConfig>,
+    difficulty: Difficulty,
     mut target_info: ResMut<TargetEmojiInfo>,
     atlas: Res<emoji::EmojiAtlas>,
     validation: Res<emoji::AtlasValidation>,
@@ -54,9 +66,11 @@ pub fn handle_stage_complete_input(
     if mouse_button_input.just_pressed(MouseButton::Left) || touch_input.any_just_pressed() {
         // Update stage configuration
         stage_config.current_stage_number += 1;
-        stage_config.stage.emoji_speed *= 1.2; // Increase speed by 20%
-        stage_config.stage.total_emojis += 5; // Add 5 more emojis
-        stage_config.stage.time_limit *= 0.9; // Reduce time by 10%
+        let stage_number = stage_config.current_stage_number as u32;
+        stage_config.stage.emoji_speed = difficulty.at_stage("emoji_speed", stage_number);
+        stage_config.stage.total_emojis =
+            difficulty.at_stage("total_emojis", stage_number).round() as usize;
+        stage_config.stage.time_limit = difficulty.at_stage("time_limit", stage_number);
 
         // Pick a new target emoji
         if let Some(&index) = emoji::get_random_emojis(&atlas, &validation, 1).first() {
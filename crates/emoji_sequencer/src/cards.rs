@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bits_helpers::WINDOW_WIDTH;
 use bits_helpers::emoji::{self, AtlasValidation, EmojiAtlas};
+use bits_helpers::loading::Preload;
 
 use crate::game::{GameDifficulty, GameProgress, GameState, SequenceState, SequenceStep};
 use crate::variables::GameVariables;
@@ -77,8 +78,8 @@ fn cleanup_stage(mut commands: Commands, cards: Query<Entity, With<Card>>) {
 }
 
 /// Loads the card back texture and inserts it as a resource.
-fn setup_cards(mut commands: Commands, asset_server: Res<AssetServer>, vars: Res<GameVariables>) {
-    let card_back = asset_server.load(vars.card_back_path);
+fn setup_cards(mut commands: Commands, mut preload: Preload, vars: Res<GameVariables>) {
+    let card_back = preload.load(&vars.card_back_path);
     commands.insert_resource(CardBackTexture(card_back));
 }
 
@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::Difficulty;
+use bits_helpers::score::{ResultPolicy, ScoreKeeper};
 use bits_helpers::send_bit_message;
 
 use crate::cards::Card;
@@ -14,6 +16,15 @@ impl Plugin for GamePlugin {
             .init_resource::<SequenceState>()
             .init_resource::<GameProgress>()
             .init_resource::<ScoreState>()
+            .insert_resource(
+                ScoreKeeper::new(ResultPolicy::HighestScore)
+                    .with_stars([300, 750, 1500])
+                    .with_ranks([
+                        ("rank.bronze", 300),
+                        ("rank.silver", 750),
+                        ("rank.gold", 1500),
+                    ]),
+            )
             .init_resource::<FeedbackState>()
             .add_systems(Update, handle_stage_transition)
             .add_systems(
@@ -79,9 +90,14 @@ pub struct GameProgress {
     pub attempt_timer: Timer,
 }
 
-impl Default for GameProgress {
-    fn default() -> Self {
-        let vars = GameVariables::default();
+impl FromWorld for GameProgress {
+    fn from_world(world: &mut World) -> Self {
+        Self::new(world.resource::<GameVariables>())
+    }
+}
+
+impl GameProgress {
+    pub fn new(vars: &GameVariables) -> Self {
         Self {
             sequence_step: SequenceStep::SpawningSequence,
             step_timer: None,
@@ -93,9 +109,7 @@ impl Default for GameProgress {
             attempt_timer: Timer::from_seconds(0.0, TimerMode::Once),
         }
     }
-}
 
-impl GameProgress {
     /// Records a mistake and returns whether game is over
     pub fn record_mistake(&mut self, vars: &GameVariables) -> bool {
         self.mistakes += 1;
@@ -131,9 +145,14 @@ pub struct GameDifficulty {
     pub total_emojis: usize,
 }
 
-impl Default for GameDifficulty {
-    fn default() -> Self {
-        let vars = GameVariables::default();
+impl FromWorld for GameDifficulty {
+    fn from_world(world: &mut World) -> Self {
+        Self::new(world.resource::<GameVariables>())
+    }
+}
+
+impl GameDifficulty {
+    pub const fn new(vars: &GameVariables) -> Self {
         Self {
             stage: 1,
             sequence_length: vars.initial_sequence_length,
@@ -143,26 +162,17 @@ impl Default for GameDifficulty {
             total_emojis: vars.initial_total_emojis,
         }
     }
-}
 
-impl GameDifficulty {
     /// Progress to next stage and recalculate difficulty parameters
-    pub fn advance_stage(&mut self) {
+    pub fn advance_stage(&mut self, difficulty: &Difficulty) {
         self.stage += 1;
-
-        // Increase sequence length every 2 stages
-        if self.stage % 2 == 0 {
-            self.sequence_length += 1;
-        }
-
-        // Always recalculate difficulty when advancing
-        self.recalculate_difficulty();
+        self.recalculate_difficulty(difficulty);
     }
 
     /// Calculate difficulty parameters based on current stage
-    fn recalculate_difficulty(&mut self) {
-        // Increase total emojis every 2 stages
-        self.total_emojis = 6 + (self.stage / 2) as usize;
+    fn recalculate_difficulty(&mut self, difficulty: &Difficulty) {
+        self.sequence_length = difficulty.at_stage("sequence_length", self.stage).round() as u32;
+        self.total_emojis = difficulty.at_stage("total_emojis", self.stage).round() as usize;
 
         // Adjust grid dimensions based on total emojis
         self.grid_cols = (self.total_emojis as f32).sqrt().ceil() as u32;
@@ -184,9 +194,7 @@ pub struct SequenceState {
 
 #[derive(Resource, Default)]
 pub struct ScoreState {
-    /// Total accumulated score
-    pub total_score: u32,
-    /// Score for current stage
+    /// Score for current stage, the total being kept by the [`ScoreKeeper`]
     pub stage_score: u32,
 }
 
@@ -196,10 +204,12 @@ fn handle_stage_transition(
     celebration_state: Res<CelebrationState>,
     mut stage_state: ResMut<StageState>,
     mut game_difficulty: ResMut<GameDifficulty>,
+    difficulty: Difficulty,
     mut game_progress: ResMut<GameProgress>,
     mut sequence_state: ResMut<SequenceState>,
     mut next_state: ResMut<NextState<GameState>>,
     cards: Query<Entity, With<Card>>,
+    vars: Res<GameVariables>,
 ) {
     if stage_state.stage_complete {
         if celebration_state.is_celebrating {
@@ -212,11 +222,10 @@ fn handle_stage_transition(
         }
 
         // Update difficulty
-        game_difficulty.advance_stage();
-        game_difficulty.recalculate_difficulty();
+        game_difficulty.advance_stage(&difficulty);
 
         // Reset game progress
-        *game_progress = GameProgress::default();
+        *game_progress = GameProgress::new(&vars);
 
         // Reset sequence state
         sequence_state.target_sequence.clear();
@@ -239,7 +248,7 @@ fn handle_game_over_sequence(
     mut game_progress: ResMut<GameProgress>,
     mut cards: Query<(Entity, &mut Card)>,
     mut next_state: ResMut<NextState<GameState>>,
-    score_state: Res<ScoreState>,
+    score: Res<ScoreKeeper>,
 ) {
     if game_progress.game_over && game_progress.game_over_reveal_timer.is_some() {
         // Show all cards
@@ -254,9 +263,7 @@ fn handle_game_over_sequence(
                     commands.entity(entity).despawn_recursive();
                 }
                 next_state.set(GameState::GameOver);
-                send_bit_message(ribbit_bits::BitMessage::End(
-                    ribbit_bits::BitResult::HighestScore(score_state.total_score.into()),
-                ));
+                send_bit_message(ribbit_bits::BitMessage::End(score.result()));
             }
         }
     }
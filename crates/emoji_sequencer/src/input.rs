@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bits_helpers::input::just_pressed_world_position;
+use bits_helpers::score::{ScoreEvent, ScoreKeeper};
 
 use crate::cards::Card;
 use crate::game::{
@@ -38,6 +39,7 @@ fn handle_card_clicks(
     mut game_progress: ResMut<GameProgress>,
     mut stage_state: ResMut<StageState>,
     mut score_state: ResMut<ScoreState>,
+    mut score: ResMut<ScoreKeeper>,
     mut feedback_state: ResMut<FeedbackState>,
     mut sprite_query: Query<&mut Sprite>,
     difficulty: Res<GameDifficulty>,
@@ -130,7 +132,8 @@ fn handle_card_clicks(
         };
 
         score_state.stage_score = vars.stage_completion_score + speed_bonus;
-        score_state.total_score += score_state.stage_score;
+        score.record(ScoreEvent::Points(vars.stage_completion_score));
+        score.record(ScoreEvent::Bonus(speed_bonus));
         stage_state.stage_complete = true;
         stage_state.transition_timer = Some(Timer::from_seconds(
             vars.stage_transition_duration,
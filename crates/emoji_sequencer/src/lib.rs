@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use bits_helpers::debug::DebugAppExt;
+use bits_helpers::difficulty::DifficultyPlugin;
 use bits_helpers::emoji::EmojiPlugin;
 
 use crate::game::GameState;
@@ -26,6 +28,10 @@ pub fn run() {
         env!("CARGO_PKG_VERSION"),
     )
     .add_plugins(EmojiPlugin)
+    .add_plugins(DifficultyPlugin::new(
+        "stages.difficulty.ron",
+        include_str!("../assets/stages.difficulty.ron"),
+    ))
     .add_plugins(GameVariablesPlugin)
     .add_plugins(CardPlugin)
     .add_plugins(InputPlugin)
@@ -33,6 +39,8 @@ pub fn run() {
     .add_plugins(EffectsPlugin)
     .add_plugins(AnimationPlugin)
     .init_state::<GameState>()
+    .debug_state::<GameState>()
+    .debug_commands::<ribbit::EmojiSequencer>()
     .add_plugins(ScreenPlugin)
     .add_systems(Startup, setup)
     .run();
@@ -1,16 +1,25 @@
-use bevy::state::state::NextState;
+use core::time::Duration;
+
+use bevy::ecs::world::{FromWorld, World};
+use bevy::state::state::{NextState, State};
+use bevy::time::{Timer, TimerMode};
 use bits_helpers::RibbitMessageHandler;
+use bits_helpers::debug::{DebugCommandError, DebugCommands};
+use bits_helpers::score::{ScoreEvent, ScoreKeeper};
 use ribbit_bits::{BitDuration, BitResult};
 
-use crate::game::{GameDifficulty, GameProgress, GameState, ScoreState};
+use crate::game::{GameDifficulty, GameProgress, GameState, ScoreState, StageState};
+use crate::variables::GameVariables;
 
 #[derive(Default, Clone, Copy)]
 pub struct EmojiSequencer;
 
 impl RibbitMessageHandler for EmojiSequencer {
     fn restart(world: &mut bevy::prelude::World) {
-        world.insert_resource(GameDifficulty::default());
-        world.insert_resource(GameProgress::default());
+        let difficulty = GameDifficulty::from_world(world);
+        world.insert_resource(difficulty);
+        let progress = GameProgress::from_world(world);
+        world.insert_resource(progress);
         world.insert_resource(ScoreState::default());
 
         let mut next_state = world.resource_mut::<NextState<GameState>>();
@@ -21,12 +30,84 @@ impl RibbitMessageHandler for EmojiSequencer {
         let mut next_state = world.resource_mut::<NextState<GameState>>();
         next_state.set(GameState::GameOver);
 
-        let score = world.resource::<ScoreState>();
-
-        BitResult::HighestScore(score.total_score.into())
+        world.resource::<ScoreKeeper>().result()
     }
 
     fn duration(_world: &mut bevy::prelude::World) -> BitDuration {
         BitDuration::max_duration()
     }
 }
+
+impl DebugCommands for EmojiSequencer {
+    /// Completes the current stage, the next one being `stage`
+    fn jump_to_stage(world: &mut World, stage: u32) -> Result<(), DebugCommandError> {
+        ensure_playing(world)?;
+        if stage == 0 {
+            return Err(DebugCommandError::Rejected("Stages start at 1".to_string()));
+        }
+
+        world.resource_mut::<GameDifficulty>().stage = stage - 1;
+        world.resource_mut::<StageState>().stage_complete = true;
+        Ok(())
+    }
+
+    fn set_score(world: &mut World, score: i64) -> Result<(), DebugCommandError> {
+        let score = u32::try_from(score)
+            .map_err(|error| DebugCommandError::Rejected(format!("{score}: {error}")))?;
+        world.resource_mut::<ScoreKeeper>().set_score(score);
+        Ok(())
+    }
+
+    /// Slows down the current attempt, lowering its speed bonus
+    fn add_time(world: &mut World, seconds: f32) -> Result<(), DebugCommandError> {
+        ensure_playing(world)?;
+
+        let mut progress = world.resource_mut::<GameProgress>();
+        let elapsed =
+            Duration::try_from_secs_f32((progress.attempt_timer.elapsed_secs() + seconds).max(0.0))
+                .map_err(|error| DebugCommandError::Rejected(format!("{seconds}: {error}")))?;
+        progress.attempt_timer.set_elapsed(elapsed);
+        Ok(())
+    }
+
+    /// Completes the current stage as if the sequence was played perfectly
+    fn win(world: &mut World) -> Result<(), DebugCommandError> {
+        ensure_playing(world)?;
+
+        let vars = world.resource::<GameVariables>().clone();
+        world.resource_mut::<ScoreState>().stage_score =
+            vars.stage_completion_score + vars.max_speed_bonus;
+        let mut score = world.resource_mut::<ScoreKeeper>();
+        score.record(ScoreEvent::Points(vars.stage_completion_score));
+        score.record(ScoreEvent::Bonus(vars.max_speed_bonus));
+
+        let mut stage_state = world.resource_mut::<StageState>();
+        stage_state.stage_complete = true;
+        stage_state.transition_timer = Some(Timer::from_seconds(
+            vars.stage_transition_duration,
+            TimerMode::Once,
+        ));
+        Ok(())
+    }
+
+    /// Makes the mistakes left at once, ending the game
+    fn lose(world: &mut World) -> Result<(), DebugCommandError> {
+        ensure_playing(world)?;
+
+        let vars = world.resource::<GameVariables>().clone();
+        let mut progress = world.resource_mut::<GameProgress>();
+        progress.mistakes = progress.max_mistakes.saturating_sub(1);
+        progress.record_mistake(&vars);
+        Ok(())
+    }
+}
+
+fn ensure_playing(world: &World) -> Result<(), DebugCommandError> {
+    if *world.resource::<State<GameState>>().get() == GameState::Playing {
+        Ok(())
+    } else {
+        Err(DebugCommandError::Rejected(
+            "The game is not started".to_string(),
+        ))
+    }
+}
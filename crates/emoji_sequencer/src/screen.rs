@@ -1,5 +1,8 @@
 use bevy::prelude::*;
-use bits_helpers::{FONT, WINDOW_HEIGHT, WINDOW_WIDTH};
+use bits_helpers::FONT;
+use bits_helpers::hud::{HudLives, HudPlugin, HudStage};
+use bits_helpers::i18n::Strings;
+use bits_helpers::score::ScoreKeeper;
 
 use crate::game::{GameDifficulty, GameProgress, GameState, ScoreState};
 use crate::variables::GameVariables;
@@ -10,9 +13,6 @@ pub struct WelcomeScreen;
 #[derive(Component)]
 pub struct StageTransitionScreen;
 
-#[derive(Component)]
-pub struct ScoreDisplay;
-
 pub struct ScreenPlugin;
 
 impl Plugin for ScreenPlugin {
@@ -38,11 +38,29 @@ impl Plugin for ScreenPlugin {
             OnExit(GameState::StageComplete),
             despawn_screen::<StageTransitionScreen>,
         )
-        .add_systems(
-            Update,
-            (spawn_score_display, update_score_display).run_if(in_state(GameState::Playing)),
-        )
-        .add_systems(OnExit(GameState::Playing), despawn_screen::<ScoreDisplay>);
+        .add_plugins(
+            HudPlugin::new()
+                .with_score::<ScoreKeeper>()
+                .with_lives::<GameProgress>()
+                .with_stage::<GameDifficulty>()
+                .in_state(GameState::Playing),
+        );
+    }
+}
+
+impl HudLives for GameProgress {
+    fn lives(&self) -> u32 {
+        self.max_mistakes.saturating_sub(self.mistakes)
+    }
+
+    fn max_lives(&self) -> u32 {
+        self.max_mistakes
+    }
+}
+
+impl HudStage for GameDifficulty {
+    fn stage(&self) -> u32 {
+        self.stage
     }
 }
 
@@ -55,6 +73,7 @@ fn despawn_screen<T: Component>(mut commands: Commands, query: Query<Entity, Wit
 fn try_spawn_welcome_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    strings: Strings,
     vars: Res<GameVariables>,
     query: Query<&WelcomeScreen>,
 ) {
@@ -64,7 +83,7 @@ fn try_spawn_welcome_screen(
 
     commands.spawn((
         WelcomeScreen,
-        Text2d::new("Emoji Sequencer\nPress to Start".to_string()),
+        Text2d::new(strings.get("emoji_sequencer.welcome")),
         TextFont {
             font: asset_server.load(FONT),
             font_size: vars.welcome_font_size,
@@ -88,10 +107,12 @@ fn handle_welcome_input(
 fn try_spawn_stage_transition(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    strings: Strings,
     vars: Res<GameVariables>,
     difficulty: Res<GameDifficulty>,
     game_progress: Res<GameProgress>,
     score_state: Res<ScoreState>,
+    score: Res<ScoreKeeper>,
     query: Query<&StageTransitionScreen>,
 ) {
     if !query.is_empty() {
@@ -100,15 +121,20 @@ fn try_spawn_stage_transition(
 
     commands.spawn((
         StageTransitionScreen,
-        Text2d::new(format!(
-            "Stage {} Complete!\n\nScore: +{}\nTotal Score: {}\nMistakes: {}/{}\n\nNext Stage:\nSequence Length: {}\nReveal Time: {:.1}s\n\nClick to Continue",
-            difficulty.stage,
-            score_state.stage_score,
-            score_state.total_score,
-            game_progress.mistakes,
-            game_progress.max_mistakes,
-            difficulty.sequence_length,
-            difficulty.sequence_length as f32 * vars.reveal_time_per_emoji
+        Text2d::new(strings.format(
+            "emoji_sequencer.stage_complete",
+            &[
+                ("stage", difficulty.stage.into()),
+                ("stage_score", score_state.stage_score.into()),
+                ("score", score.total().into()),
+                ("mistakes", game_progress.mistakes.into()),
+                ("max_mistakes", game_progress.max_mistakes.into()),
+                ("length", difficulty.sequence_length.into()),
+                (
+                    "reveal_time",
+                    (difficulty.sequence_length as f32 * vars.reveal_time_per_emoji).into(),
+                ),
+            ],
         )),
         TextFont {
             font: asset_server.load(FONT),
@@ -129,43 +155,3 @@ fn handle_stage_transition_input(
         next_state.set(GameState::Playing);
     }
 }
-
-fn spawn_score_display(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    vars: Res<GameVariables>,
-    query: Query<&ScoreDisplay>,
-) {
-    if !query.is_empty() {
-        return;
-    }
-
-    commands.spawn((
-        ScoreDisplay,
-        Text2d::new(String::new()),
-        TextFont {
-            font: asset_server.load(FONT),
-            font_size: vars.score_font_size,
-            ..default()
-        },
-        TextLayout::new_with_justify(JustifyText::Left),
-        Transform::from_xyz(WINDOW_HEIGHT, WINDOW_WIDTH, 0.0),
-    ));
-}
-
-fn update_score_display(
-    mut query: Query<&mut Text2d, With<ScoreDisplay>>,
-    difficulty: Res<GameDifficulty>,
-    game_progress: Res<GameProgress>,
-    score_state: Res<ScoreState>,
-) {
-    for mut text in &mut query {
-        text.0 = format!(
-            "Stage: {}\nScore: {}\nMistakes: {}/{}",
-            difficulty.stage,
-            score_state.total_score,
-            game_progress.mistakes,
-            game_progress.max_mistakes
-        );
-    }
-}
@@ -1,16 +1,22 @@
 use bevy::prelude::*;
+use bits_helpers::tuning::{Tuning, TuningError, TuningPlugin, check_range, hex_color};
+use serde::Deserialize;
 
 /// Plugin that manages all game-related constants and configuration values
 pub struct GameVariablesPlugin;
 
 impl Plugin for GameVariablesPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(GameVariables::default());
+        app.add_plugins(TuningPlugin::<GameVariables>::new(
+            "variables.tuning.ron",
+            include_str!("../assets/variables.tuning.ron"),
+        ));
     }
 }
 
-/// Resource containing all game-related constants and configuration values
-#[derive(Resource)]
+/// Resource containing all game-related constants and configuration values,
+/// read from `assets/variables.tuning.ron`
+#[derive(Resource, Clone, Deserialize, TypePath)]
 pub struct GameVariables {
     // Card-related constants
     /// Size of each card in pixels (width and height as cards are square)
@@ -18,13 +24,16 @@ pub struct GameVariables {
     /// Vertical position for the bottom sequence cards
     pub sequence_card_y: f32,
     /// Default color for card sprites
+    #[serde(deserialize_with = "hex_color")]
     pub default_color: Color,
     /// Color used to indicate incorrect selection
+    #[serde(deserialize_with = "hex_color")]
     pub wrong_color: Color,
     /// Color used to indicate correct selection
+    #[serde(deserialize_with = "hex_color")]
     pub correct_color: Color,
     /// Path to the card back image asset
-    pub card_back_path: &'static str,
+    pub card_back_path: String,
 
     // Timing constants
     /// Duration to show each emoji in the sequence (seconds)
@@ -90,67 +99,53 @@ pub struct GameVariables {
 
     /// Font size for stage transition screen
     pub stage_transition_font_size: f32,
-    /// Font size for score display
-    pub score_font_size: f32,
 
     // Interaction settings
     /// Click detection radius for cards
     pub card_click_radius: f32,
 }
 
-impl Default for GameVariables {
-    fn default() -> Self {
-        Self {
-            // Card constants
-            card_size: 140.0,
-            sequence_card_y: -200.0,
-            default_color: Color::WHITE,
-            wrong_color: Color::srgb(1.0, 0.0, 0.0),
-            correct_color: Color::srgb(0.0, 1.0, 0.0),
-            card_back_path: "card_back.png",
-
-            // Timing constants
-            reveal_time_per_emoji: 1.0,
-            sequence_complete_delay: 1.0,
-            mismatch_delay: 0.5,
-
-            // Game rules
-            max_mistakes: 3,
-            stage_completion_score: 100,
-            max_speed_bonus: 50,
-            speed_bonus_threshold: 5.0,
-
-            // Initial difficulty settings
-            initial_sequence_length: 3,
-            initial_grid_cols: 3,
-            initial_grid_rows: 2,
-            initial_grid_spacing: 80.0,
-            initial_total_emojis: 6,
-
-            // Game over settings
-            game_over_reveal_duration: 3.0,
-            stage_transition_duration: 2.0,
-
-            // Effect settings
-            celebration_particle_count: 30,
-            celebration_duration: 1.5,
-            celebration_particle_size: 10.0,
-            feedback_particle_count: 10,
-            feedback_particle_duration: 0.75,
-            feedback_particle_size: 5.0,
-
-            // Ring effect settings
-            ring_effect_duration: 2.0,
-            ring_effect_margin: 30.0,
-            ring_effect_rotation_speed: 1.0,
-
-            // UI settings
-            welcome_font_size: 32.0,
-            stage_transition_font_size: 32.0,
-            score_font_size: 24.0,
-
-            // Interaction settings
-            card_click_radius: 35.0,
-        }
+impl Tuning for GameVariables {
+    fn validate(&self) -> Result<(), TuningError> {
+        check_range("card_size", self.card_size, 1.0..)?;
+        check_range("reveal_time_per_emoji", self.reveal_time_per_emoji, 0.1..)?;
+        check_range(
+            "sequence_complete_delay",
+            self.sequence_complete_delay,
+            0.0..,
+        )?;
+        check_range("mismatch_delay", self.mismatch_delay, 0.0..)?;
+        check_range("max_mistakes", self.max_mistakes, 1..)?;
+        check_range("speed_bonus_threshold", self.speed_bonus_threshold, 0.1..)?;
+        check_range("initial_sequence_length", self.initial_sequence_length, 1..)?;
+        check_range("initial_grid_cols", self.initial_grid_cols, 1..)?;
+        check_range("initial_grid_rows", self.initial_grid_rows, 1..)?;
+        check_range(
+            "initial_total_emojis",
+            self.initial_total_emojis,
+            self.initial_sequence_length as usize..,
+        )?;
+        check_range(
+            "game_over_reveal_duration",
+            self.game_over_reveal_duration,
+            0.0..,
+        )?;
+        check_range(
+            "stage_transition_duration",
+            self.stage_transition_duration,
+            0.0..,
+        )?;
+        check_range(
+            "celebration_particle_count",
+            self.celebration_particle_count,
+            0..=500,
+        )?;
+        check_range(
+            "feedback_particle_count",
+            self.feedback_particle_count,
+            0..=100,
+        )?;
+        check_range("ring_effect_duration", self.ring_effect_duration, 0.1..)?;
+        check_range("card_click_radius", self.card_click_radius, 1.0..)
     }
 }
@@ -1,65 +1,38 @@
 use bevy::prelude::*;
-use bevy_asset_loader::prelude::*;
-use bevy_kira_audio::prelude::*;
+use bits_helpers::audio::{BitAudioPlugin, PlaySound, SoundBank, SoundDef, SoundEvent};
 
 use crate::gameplay::{GameState, JumpedEvent, ScoredEvent};
 
-#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
-enum AssetState {
-    #[default]
-    Loading,
-    Loaded,
-}
-
-#[derive(AssetCollection, Resource)]
-struct AudioAssets {
-    #[asset(path = "audio/pickupCoin.ogg")]
-    coin: Handle<bevy_kira_audio::prelude::AudioSource>,
-    #[asset(path = "audio/explosion.ogg")]
-    gun: Handle<bevy_kira_audio::prelude::AudioSource>,
-    #[asset(path = "audio/hitHurt.ogg")]
-    death: Handle<bevy_kira_audio::prelude::AudioSource>,
-}
-
 pub struct GameAudioPlugin;
 
 impl Plugin for GameAudioPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(AudioPlugin)
-            .init_state::<AssetState>()
-            .add_loading_state(
-                LoadingState::new(AssetState::Loading)
-                    .continue_to_state(AssetState::Loaded)
-                    .load_collection::<AudioAssets>(),
-            )
-            .add_systems(
-                Update,
-                (score_audio, jump_audio).run_if(in_state(AssetState::Loaded)),
-            )
-            .add_systems(OnEnter(GameState::Dead), death_audio);
+        app.add_plugins(BitAudioPlugin::new(
+            SoundBank::default()
+                .with(SoundEvent::Success, SoundDef::new("audio/pickupCoin.ogg"))
+                .with(
+                    SoundEvent::Tap,
+                    SoundDef::new("audio/explosion.ogg").with_pitch_variation(0.05),
+                )
+                .with(SoundEvent::Fail, SoundDef::new("audio/hitHurt.ogg")),
+        ))
+        .add_systems(Update, (score_audio, jump_audio))
+        .add_systems(OnEnter(GameState::Dead), death_audio);
     }
 }
 
-fn death_audio(audio_assets: Res<AudioAssets>, audio: Res<Audio>) {
-    audio.play(audio_assets.death.clone_weak());
+fn death_audio(mut sounds: EventWriter<PlaySound>) {
+    sounds.send(PlaySound(SoundEvent::Fail));
 }
 
-fn score_audio(
-    audio_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    mut score_event: EventReader<ScoredEvent>,
-) {
+fn score_audio(mut sounds: EventWriter<PlaySound>, mut score_event: EventReader<ScoredEvent>) {
     for _ in score_event.read() {
-        audio.play(audio_assets.coin.clone_weak());
+        sounds.send(PlaySound(SoundEvent::Success));
     }
 }
 
-fn jump_audio(
-    audio_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    mut jump_event: EventReader<JumpedEvent>,
-) {
+fn jump_audio(mut sounds: EventWriter<PlaySound>, mut jump_event: EventReader<JumpedEvent>) {
     for _ in jump_event.read() {
-        audio.play(audio_assets.gun.clone_weak());
+        sounds.send(PlaySound(SoundEvent::Tap));
     }
 }
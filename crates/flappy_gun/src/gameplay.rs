@@ -4,6 +4,7 @@ use avian3d::math::Quaternion;
 use avian3d::prelude::*;
 use bevy::prelude::*;
 use bevy::time::common_conditions::on_timer;
+use bits_helpers::storage::Storage;
 use serde::{Deserialize, Serialize};
 
 use crate::player::controls::{check_for_game_start, jump};
@@ -46,6 +47,7 @@ impl Plugin for StateTransitionPlugin {
             .add_event::<JumpedEvent>()
             .add_systems(OnEnter(GameState::Ready), spawn_level)
             .add_systems(OnEnter(GameState::Playing), start_game)
+            .add_systems(OnEnter(GameState::Dead), save_high_score)
             .add_systems(
                 Update,
                 (
@@ -60,10 +62,18 @@ impl Plugin for StateTransitionPlugin {
                     scoring,
                 ),
             )
-            .add_systems(Startup, setup);
+            .add_systems(Startup, (setup, load_high_score));
     }
 }
 
+fn load_high_score(storage: Res<Storage>, mut score_info: ResMut<ScoreInfo>) {
+    score_info.high_score = storage.high_score().unwrap_or_default();
+}
+
+fn save_high_score(storage: Res<Storage>, score_info: Res<ScoreInfo>) {
+    storage.submit_score(score_info.current_score);
+}
+
 fn start_game(
     mut commands: Commands,
     mut player_query: Query<(Entity, &mut LinearVelocity), With<LockedAxes>>,
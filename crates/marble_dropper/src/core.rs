@@ -70,5 +70,4 @@ pub mod config {
     pub const PLATFORM_SIZE: Vec2 = Vec2::new(100.0, 10.0);
     pub const BUCKET_SIZE: Vec2 = Vec2::new(60.0, 20.0);
     pub const GRAVITY: f32 = 980.0; // Pixels/second^2
-    pub const TIME_CHUNKS: usize = 3; // Divide game into 3 difficulty stages
 }
@@ -6,6 +6,7 @@ use avian2d::prelude::{
     Mass, Restitution, RigidBody,
 };
 use bevy::prelude::*;
+use bits_helpers::difficulty::Difficulty;
 use bits_helpers::floating_score::spawn_floating_score;
 use bits_helpers::input::pressed_world_position;
 use bits_helpers::{FONT, WINDOW_HEIGHT, WINDOW_WIDTH};
@@ -137,16 +138,19 @@ pub fn update_game(
     asset_server: Res<AssetServer>,
     mut marble_query: Query<(Entity, &Transform, &Circle, &mut Resting, &Marble), With<Marble>>,
     game_timer: Res<GameTimer>,
+    difficulty: Difficulty,
 ) {
     spawn_timer.timer.tick(time.delta());
 
     // Adjust spawn rate based on game progression
     let total_duration = game_timer.timer.duration().as_secs_f32();
     let elapsed = total_duration - game_timer.timer.remaining_secs();
-    let chunk_duration = total_duration / config::TIME_CHUNKS as f32;
-    let current_chunk = (elapsed / chunk_duration).floor() as usize;
-
-    let new_spawn_rate = (current_chunk as f32).mul_add(-0.5, 1.5).max(0.5);
+    let progress = if total_duration > 0.0 {
+        elapsed / total_duration
+    } else {
+        0.0
+    };
+    let new_spawn_rate = difficulty.sample("spawn_interval", progress);
     const EPSILON: f32 = 0.001;
     if (new_spawn_rate - spawn_timer.spawn_rate).abs() > EPSILON {
         spawn_timer.spawn_rate = new_spawn_rate;
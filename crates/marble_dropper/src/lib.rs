@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::DifficultyPlugin;
 use bits_helpers::emoji::EmojiPlugin;
 
 pub mod core;
@@ -23,6 +24,10 @@ pub fn run() {
         env!("CARGO_PKG_VERSION"),
     );
     app.add_plugins(EmojiPlugin)
+        .add_plugins(DifficultyPlugin::new(
+            "spawning.difficulty.ron",
+            include_str!("../assets/spawning.difficulty.ron"),
+        ))
         .add_plugins(PhysicsPlugin)
         .init_state::<GameState>()
         .init_resource::<GameTimer>()
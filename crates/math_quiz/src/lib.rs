@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bevy::time::Timer;
+use bits_helpers::play_clock::{PlayClock, PlayClockPlugin};
 use bits_helpers::welcome_screen::{WelcomeScreenElement, despawn_welcome_screen};
 use bits_helpers::{FONT, send_bit_message};
 use ribbit::MathQuiz;
@@ -25,7 +26,6 @@ struct GameData {
     current_stage: u32,
     current_question: String,
     correct_answer: i32,
-    timer: Timer,
     stage_entity: Option<Entity>,
     feedback_timer: Option<Timer>,
     last_answer_correct: bool,
@@ -52,7 +52,6 @@ impl Default for GameData {
             current_stage: 1,
             current_question: String::new(),
             correct_answer: 0,
-            timer: Timer::from_seconds(60.0, TimerMode::Once),
             stage_entity: None,
             feedback_timer: None,
             last_answer_correct: false,
@@ -65,6 +64,7 @@ pub fn run() {
     bits_helpers::get_default_app::<MathQuiz>(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
         .init_state::<GameState>()
         .init_resource::<GameData>()
+        .add_plugins(PlayClockPlugin::new().in_state(GameState::Playing))
         .add_systems(Startup, setup)
         .add_systems(OnEnter(GameState::Welcome), spawn_welcome_screen)
         .add_systems(OnExit(GameState::Welcome), despawn_welcome_screen)
@@ -135,6 +135,7 @@ fn spawn_game_elements(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut game_data: ResMut<GameData>,
+    mut play_clock: ResMut<PlayClock>,
     stage_text_query: Query<&mut Text, With<StageText>>,
 ) {
     commands
@@ -182,7 +183,7 @@ fn spawn_game_elements(
             ));
         });
 
-    game_data.timer.reset();
+    play_clock.reset();
     game_data.current_stage = 1;
 
     generate_question(
@@ -344,6 +345,7 @@ fn generate_question(
 fn check_answer(
     mut commands: Commands,
     mut game_data: ResMut<GameData>,
+    mut play_clock: ResMut<PlayClock>,
     interaction_query: Query<
         (Entity, &Interaction, &AnswerBox),
         (Changed<Interaction>, With<Button>),
@@ -371,6 +373,8 @@ fn check_answer(
 
             game_data.feedback_timer = Some(Timer::from_seconds(1.0, TimerMode::Once));
             game_data.waiting_for_feedback = true;
+            // The feedback is not part of the time taken to answer
+            play_clock.pause();
 
             break;
         }
@@ -382,6 +386,7 @@ fn handle_feedback_timer(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     time: Res<Time>,
+    mut play_clock: ResMut<PlayClock>,
     mut next_state: ResMut<NextState<GameState>>,
     stage_text_query: Query<&mut Text, With<StageText>>,
 ) {
@@ -392,10 +397,9 @@ fn handle_feedback_timer(
             if game_data.last_answer_correct {
                 if game_data.current_stage > 4 {
                     next_state.set(GameState::GameOver);
-                    send_bit_message(BitMessage::End(BitResult::FastestDuration(
-                        game_data.timer.elapsed(),
-                    )));
+                    send_bit_message(BitMessage::End(play_clock.fastest_result()));
                 } else {
+                    play_clock.resume();
                     generate_question(
                         &mut commands,
                         &asset_server,
@@ -413,14 +417,9 @@ fn handle_feedback_timer(
     }
 }
 
-fn update_timer(
-    time: Res<Time>,
-    mut game_data: ResMut<GameData>,
-    mut timer_query: Query<&mut Text, With<TimerText>>,
-) {
-    game_data.timer.tick(time.delta());
+fn update_timer(play_clock: Res<PlayClock>, mut timer_query: Query<&mut Text, With<TimerText>>) {
     if let Ok(mut text) = timer_query.get_single_mut() {
-        text.0 = format!("Time: {:.1}", game_data.timer.elapsed_secs());
+        text.0 = format!("Time: {:.1}", play_clock.elapsed().as_secs_f32());
     }
 }
 
@@ -3,10 +3,12 @@ use std::f32::consts::SQRT_2;
 use bevy::prelude::*;
 use bevy::text::{TextColor, TextFont};
 use bevy::utils::default;
-use bits_helpers::emoji::{self, AtlasValidation, EMOJI_SIZE, EmojiAtlas, EmojiPlugin};
+use bits_helpers::emoji::{EmojiPlugin, Emojis};
 use bits_helpers::input::{
     just_pressed_world_position, just_released_world_position, pressed_world_position,
 };
+use bits_helpers::loading::LoadingState;
+use bits_helpers::rich_text::{RichText, RichTextPlugin};
 use bits_helpers::send_bit_message;
 use maze::MazeGenerator;
 use ribbit::Maze;
@@ -100,15 +102,15 @@ struct VirtualControllerLever;
 
 pub fn run() {
     bits_helpers::get_default_app::<Maze>(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
-        .add_plugins(EmojiPlugin)
+        .add_plugins((EmojiPlugin, RichTextPlugin))
         .init_state::<GameState>()
         .insert_resource(GameManager::default())
         .add_systems(OnEnter(GameState::Init), init_enter)
+        .add_systems(OnEnter(LoadingState::Loaded), init)
         .add_systems(OnEnter(GameState::Game), reset_maze)
         .add_systems(
             Update,
             (
-                init.run_if(in_state(GameState::Init)),
                 item_collect_system.run_if(in_state(GameState::Game)),
                 gridbase_player_move_system.run_if(in_state(GameState::Game)),
                 game_result.run_if(in_state(GameState::Result)),
@@ -131,21 +133,14 @@ fn init_enter(
     commands.spawn(MazeGenerator::new(MAZE_SIZE_X, MAZE_SIZE_Y));
     // Spawn maze grid (It needs only once)
     //spawn_grid(&mut commands);
-    spawn_objective(&mut commands);
     // Virtual controller
     spawn_virtual_controller(&mut commands, &mut meshes, &mut materials);
 }
 
-fn init(
-    mut commands: Commands,
-    validation: Res<AtlasValidation>,
-    mut next_state: ResMut<NextState<GameState>>,
-) {
-    if emoji::is_emoji_system_ready(&validation) {
-        // Spawn maze grid (It needs only once)
-        spawn_grid(&mut commands);
-        next_state.set(GameState::Game);
-    }
+fn init(mut commands: Commands, mut next_state: ResMut<NextState<GameState>>) {
+    // Spawn maze grid (It needs only once)
+    spawn_grid(&mut commands);
+    next_state.set(GameState::Game);
 }
 
 fn spawn_grid(commands: &mut Commands) {
@@ -169,36 +164,23 @@ fn spawn_grid(commands: &mut Commands) {
     }
 }
 
-fn spawn_objective(commands: &mut Commands) {
-    commands
-        .spawn(Node {
+fn spawn_objective(commands: &mut Commands, rich_text: &RichText, item_index: usize) {
+    let objective = rich_text.spawn(
+        commands,
+        &format!("Collect :#{item_index}:!"),
+        Node {
+            position_type: PositionType::Absolute,
             width: Val::Percent(100.),
-            height: Val::Percent(100.),
-            display: Display::Grid,
-            //grid_template_rows: RepeatedGridTrack::fr(3, 1.),
+            justify_content: JustifyContent::Center,
             ..default()
-        })
-        .with_children(|parent| {
-            parent.spawn((
-                Node {
-                    align_self: AlignSelf::Start,
-                    justify_self: JustifySelf::Center,
-                    ..default()
-                },
-                Text::new("Collect  !"),
-                TextFont {
-                    font_size: 42.,
-                    ..default()
-                },
-                TextColor(Color::WHITE),
-            ));
-        });
-    /*
-    commands.spawn((
-        Sprite::from_color(Color::srgb(1., 1., 0.), Vec2::new(32., 32.)),
-        Transform::from_xyz(72., 296., 1.),
-    ));
-    */
+        },
+        &TextFont {
+            font_size: 42.,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+    );
+    commands.entity(objective).insert(LifespanGame);
 }
 
 fn spawn_virtual_controller(
@@ -234,8 +216,8 @@ fn reset_maze(
     mut game_manager: ResMut<GameManager>,
     mut maze_query: Query<&mut MazeGenerator>,
     mut clear_query: Query<Entity, With<LifespanGame>>,
-    atlas: Res<EmojiAtlas>,
-    validation: Res<AtlasValidation>,
+    emojis: Emojis,
+    rich_text: RichText,
 ) {
     println!("reset");
     clear_maze(&mut commands, &mut clear_query);
@@ -253,7 +235,13 @@ fn reset_maze(
         deadends.swap(i, j);
     }
 
-    let indices = emoji::get_random_emojis(&atlas, &validation, 2);
+    let indices = match emojis.pick_random(2) {
+        Ok(indices) => indices,
+        Err(err) => {
+            error!("Could not pick maze emojis: {err}");
+            return;
+        }
+    };
 
     // Rest of the function remains the same
     let loop_num = (NUM_ITEMS + 1).min(deadends.len());
@@ -265,35 +253,16 @@ fn reset_maze(
             spawn_player(
                 &mut commands,
                 *deadend,
-                &atlas,
-                &validation,
+                &emojis,
                 *indices.first().expect(""),
             );
         } else {
-            spawn_item(
-                &mut commands,
-                *deadend,
-                &atlas,
-                &validation,
-                *indices.last().expect(""),
-            );
+            spawn_item(&mut commands, *deadend, &emojis, *indices.last().expect(""));
         }
     }
 
     // for objective
-    if let Some(id) = emoji::spawn_emoji(
-        &mut commands,
-        &atlas,
-        &validation,
-        *indices.last().expect(""),
-        Transform::from_xyz(72., 296., 1.).with_scale(Vec3::new(
-            32. / EMOJI_SIZE.x as f32,
-            32. / EMOJI_SIZE.y as f32,
-            1.,
-        )),
-    ) {
-        commands.entity(id).insert(LifespanGame);
-    }
+    spawn_objective(&mut commands, &rich_text, *indices.last().expect(""));
 
     game_manager.count = 0;
 }
@@ -343,28 +312,17 @@ fn spawn_maze(commands: &mut Commands, maze: &MazeGenerator) {
     }
 }
 
-fn spawn_item(
-    commands: &mut Commands,
-    pos: IVec2,
-    atlas: &Res<EmojiAtlas>,
-    validation: &Res<AtlasValidation>,
-    index: usize,
-) {
+fn spawn_item(commands: &mut Commands, pos: IVec2, emojis: &Emojis, index: usize) {
     let grid_pos = GridPos {
         x: pos.x as usize,
         y: pos.y as usize,
         priority: 2.,
     };
-    if let Some(id) = emoji::spawn_emoji(
+    if let Ok(id) = emojis.spawn_sized(
         commands,
-        atlas,
-        validation,
         index,
-        Transform::from(grid_pos).with_scale(Vec3::new(
-            ITEM_SIZE / EMOJI_SIZE.x as f32,
-            ITEM_SIZE / EMOJI_SIZE.y as f32,
-            1.,
-        )),
+        Vec2::splat(ITEM_SIZE),
+        Transform::from(grid_pos),
     ) {
         commands
             .entity(id)
@@ -373,28 +331,17 @@ fn spawn_item(
     }
 }
 
-fn spawn_player(
-    commands: &mut Commands,
-    pos: IVec2,
-    atlas: &Res<EmojiAtlas>,
-    validation: &Res<AtlasValidation>,
-    index: usize,
-) {
+fn spawn_player(commands: &mut Commands, pos: IVec2, emojis: &Emojis, index: usize) {
     let grid_pos = GridPos {
         x: pos.x as usize,
         y: pos.y as usize,
         priority: 3.,
     };
-    if let Some(id) = emoji::spawn_emoji(
+    if let Ok(id) = emojis.spawn_sized(
         commands,
-        atlas,
-        validation,
         index,
-        Transform::from(grid_pos).with_scale(Vec3::new(
-            PLAYER_SIZE / EMOJI_SIZE.x as f32,
-            PLAYER_SIZE / EMOJI_SIZE.y as f32,
-            1.,
-        )),
+        Vec2::splat(PLAYER_SIZE),
+        Transform::from(grid_pos),
     ) {
         commands
             .entity(id)
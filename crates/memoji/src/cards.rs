@@ -1,5 +1,6 @@
 use bevy::prelude::{ParamSet, *};
 use bits_helpers::emoji::{self, AtlasValidation, EmojiAtlas};
+use bits_helpers::loading::Preload;
 
 use crate::game::{FlipState, GameDifficulty, GameProgress, GameState, StageState};
 
@@ -62,9 +63,9 @@ impl Plugin for CardPlugin {
     }
 }
 
-fn setup_cards(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_cards(mut commands: Commands, mut preload: Preload) {
     // Load the card back texture
-    let card_back = asset_server.load(CARD_BACK);
+    let card_back = preload.load(CARD_BACK);
     commands.insert_resource(CardBackTexture(card_back));
 }
 
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::Difficulty;
 use bits_helpers::send_bit_message;
 use ribbit_bits::{BitMessage, BitResult};
 
@@ -136,16 +137,14 @@ impl Default for GameDifficulty {
 
 impl GameDifficulty {
     /// Progress to next stage and recalculate difficulty parameters
-    pub fn advance_stage(&mut self) {
+    pub fn advance_stage(&mut self, difficulty: &Difficulty) {
         self.stage += 1;
-        self.recalculate_difficulty();
+        self.recalculate_difficulty(difficulty);
     }
 
     /// Calculate difficulty parameters based on current stage
-    fn recalculate_difficulty(&mut self) {
-        // Start with 6 cards (3 pairs), add 2 cards (1 pair) every 2 stages
-        let total_cards = 6 + (self.stage / 2) * 2;
-        let total_cards = total_cards.min(24); // Cap at 24 cards
+    fn recalculate_difficulty(&mut self, difficulty: &Difficulty) {
+        let total_cards = difficulty.at_stage("total_cards", self.stage).round() as u32;
 
         // Adjust grid dimensions based on total cards
         self.grid_cols = (total_cards as f32).sqrt().ceil() as u32;
@@ -165,6 +164,7 @@ fn handle_stage_transition(
     celebration_state: Res<CelebrationState>,
     mut stage_state: ResMut<StageState>,
     mut game_difficulty: ResMut<GameDifficulty>,
+    difficulty: Difficulty,
     mut game_progress: ResMut<GameProgress>,
     mut next_state: ResMut<NextState<GameState>>,
     cards: Query<Entity, With<Card>>,
@@ -188,7 +188,7 @@ fn handle_stage_transition(
         }
 
         // Prep next stage
-        game_difficulty.advance_stage();
+        game_difficulty.advance_stage(&difficulty);
         *game_progress = GameProgress {
             initial_wait_timer: Some(Timer::from_seconds(INITIAL_WAIT_TIME, TimerMode::Once)),
             reveal_timer: Some(Timer::from_seconds(
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bits_helpers::difficulty::DifficultyPlugin;
 use bits_helpers::emoji::EmojiPlugin;
 
 use crate::game::GameState;
@@ -22,6 +23,10 @@ pub fn run() {
         env!("CARGO_PKG_VERSION"),
     )
     .add_plugins(EmojiPlugin)
+    .add_plugins(DifficultyPlugin::new(
+        "stages.difficulty.ron",
+        include_str!("../assets/stages.difficulty.ron"),
+    ))
     .add_plugins(CardPlugin)
     .add_plugins(InputPlugin)
     .add_plugins(GamePlugin)
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bits_helpers::FONT;
+use bits_helpers::i18n::Strings;
 
 use crate::game::{GameDifficulty, GameProgress, GameState, REVEAL_TIME_PER_CARD};
 
@@ -46,6 +47,7 @@ fn despawn_screen<T: Component>(mut commands: Commands, query: Query<Entity, Wit
 fn try_spawn_welcome_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    strings: Strings,
     query: Query<&WelcomeScreen>,
 ) {
     if !query.is_empty() {
@@ -54,7 +56,7 @@ fn try_spawn_welcome_screen(
 
     commands.spawn((
         WelcomeScreen,
-        Text2d::new("Memoji\nClick to Start".to_string()),
+        Text2d::new(strings.get("memoji.welcome")),
         TextFont {
             font: asset_server.load(FONT),
             font_size: 32.0,
@@ -78,6 +80,7 @@ fn handle_welcome_input(
 fn try_spawn_stage_transition(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    strings: Strings,
     difficulty: Res<GameDifficulty>,
     game_progress: Res<GameProgress>,
     query: Query<&StageTransitionScreen>,
@@ -86,16 +89,20 @@ fn try_spawn_stage_transition(
         return;
     }
 
+    let reveal_time = (difficulty.grid_rows * difficulty.grid_cols) as f32 * REVEAL_TIME_PER_CARD;
+
     commands.spawn((
         StageTransitionScreen,
-        Text2d::new(format!(
-            "Stage {} Complete!\n\nMistakes: {}/{}\nNext Stage: {}x{} Grid\nReveal Time: {:.1}s\n\nClick to Continue",
-            difficulty.stage,
-            game_progress.mistakes,
-            game_progress.max_mistakes,
-            difficulty.grid_rows,
-            difficulty.grid_cols,
-            (difficulty.grid_rows * difficulty.grid_cols) as f32 * REVEAL_TIME_PER_CARD
+        Text2d::new(strings.format(
+            "memoji.stage_complete",
+            &[
+                ("stage", difficulty.stage.into()),
+                ("mistakes", game_progress.mistakes.into()),
+                ("max_mistakes", game_progress.max_mistakes.into()),
+                ("rows", difficulty.grid_rows.into()),
+                ("columns", difficulty.grid_cols.into()),
+                ("reveal_time", reveal_time.into()),
+            ],
         )),
         TextFont {
             font: asset_server.load(FONT),
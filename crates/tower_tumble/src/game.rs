@@ -1,17 +1,20 @@
 use bevy::color::palettes::css;
 use bevy::prelude::*;
+use bits_helpers::score::{ResultPolicy, ScoreEvent, ScoreKeeper};
 use bits_helpers::{FONT, send_bit_message};
 use ribbit_bits::BitMessage;
 
 pub struct GamePlugin;
 
 const INITIAL_WAIT_TIME: f32 = 1.0;
-const MAX_BLOCKS_REMOVED: u32 = 15;
+pub const MAX_BLOCKS_REMOVED: u32 = 15;
 const LEVEL_TIME_LIMIT: f32 = 90.0;
+const BLOCK_SCORE: u32 = 10;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GameProgress>()
+            .insert_resource(ScoreKeeper::new(ResultPolicy::HighestScore))
             .init_resource::<LevelSettings>()
             .add_systems(Update, handle_level_transition)
             .add_systems(
@@ -37,7 +40,6 @@ pub enum GameState {
 
 #[derive(Resource)]
 pub struct GameProgress {
-    pub score: u32,
     pub level_timer: Timer,
     pub blocks_removed: u32,
     pub tower_collapsed: bool,
@@ -48,7 +50,6 @@ pub struct GameProgress {
 impl Default for GameProgress {
     fn default() -> Self {
         Self {
-            score: 0,
             level_timer: Timer::from_seconds(LEVEL_TIME_LIMIT, TimerMode::Once),
             blocks_removed: 0,
             tower_collapsed: false,
@@ -59,9 +60,9 @@ impl Default for GameProgress {
 }
 
 impl GameProgress {
-    pub fn record_block_removal(&mut self) -> bool {
+    pub fn record_block_removal(&mut self, score: &mut ScoreKeeper) -> bool {
         self.blocks_removed += 1;
-        self.score += 10;
+        score.record(ScoreEvent::Points(BLOCK_SCORE));
 
         if self.blocks_removed >= MAX_BLOCKS_REMOVED {
             self.level_complete = true;
@@ -78,10 +79,12 @@ impl GameProgress {
         self.initial_wait_timer.is_some() || self.level_complete || self.tower_collapsed
     }
 
-    pub fn add_time_bonus(&mut self) {
+    /// Records the bonus for the time left in the level, and returns it
+    pub fn add_time_bonus(&self, score: &mut ScoreKeeper) -> u32 {
         let remaining_time = self.level_timer.remaining_secs();
         let bonus = (remaining_time as u32) * 5;
-        self.score += bonus;
+        score.record(ScoreEvent::Bonus(bonus));
+        bonus
     }
 }
 
@@ -219,14 +222,13 @@ fn update_game_timer(
 
 fn check_level_complete(
     game_progress: Res<GameProgress>,
+    score: Res<ScoreKeeper>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     if game_progress.level_complete {
         next_state.set(GameState::LevelComplete);
     } else if game_progress.tower_collapsed {
-        send_bit_message(BitMessage::End(ribbit_bits::BitResult::HighestScore(
-            game_progress.score.into(),
-        )));
+        send_bit_message(BitMessage::End(score.result()));
         next_state.set(GameState::GameOver);
     }
 }
@@ -240,7 +242,6 @@ fn handle_level_transition(
         level_settings.advance_level();
 
         *game_progress = GameProgress {
-            score: game_progress.score,
             level_timer: Timer::from_seconds(LEVEL_TIME_LIMIT, TimerMode::Once),
             blocks_removed: 0,
             tower_collapsed: false,
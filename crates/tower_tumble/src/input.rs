@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bits_helpers::input::{
     just_pressed_world_position, just_released_world_position, pressed_world_position,
 };
+use bits_helpers::score::ScoreKeeper;
 
 use crate::game::{GameProgress, GameState};
 use crate::physics::TowerBlock;
@@ -171,6 +172,7 @@ fn handle_block_release(
     mut blocks: Query<(&Transform, &mut TowerBlock)>,
     mut interaction_state: ResMut<InteractionState>,
     mut game_progress: ResMut<GameProgress>,
+    mut score: ResMut<ScoreKeeper>,
 ) {
     let Some(entity) = interaction_state.grabbed_entity else {
         return;
@@ -186,7 +188,7 @@ fn handle_block_release(
             let displacement = (block_pos - initial_pos).length();
 
             if displacement > EXTRACTION_THRESHOLD {
-                game_progress.record_block_removal();
+                game_progress.record_block_removal(&mut score);
             }
 
             block.being_grabbed = false;
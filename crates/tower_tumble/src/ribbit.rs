@@ -1,5 +1,6 @@
 use bevy::state::state::NextState;
 use bits_helpers::RibbitMessageHandler;
+use bits_helpers::score::ScoreKeeper;
 use ribbit_bits::{BitDuration, BitResult};
 
 use crate::game::{GameProgress, GameState};
@@ -20,9 +21,7 @@ impl RibbitMessageHandler for TowerTumble {
         let mut next_state = world.resource_mut::<NextState<GameState>>();
         next_state.set(GameState::GameOver);
 
-        // Return final score
-        let progress = world.resource::<GameProgress>();
-        BitResult::HighestScore(progress.score.into())
+        world.resource::<ScoreKeeper>().result()
     }
 
     fn duration(_world: &mut bevy::prelude::World) -> BitDuration {
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use bits_helpers::FONT;
+use bits_helpers::i18n::Strings;
+use bits_helpers::score::ScoreKeeper;
 
-use crate::game::{GameProgress, GameState, LevelSettings};
+use crate::game::{GameProgress, GameState, LevelSettings, MAX_BLOCKS_REMOVED};
 
 #[derive(Component)]
 pub struct WelcomeScreen;
@@ -60,6 +62,7 @@ fn despawn_screen<T: Component>(mut commands: Commands, query: Query<Entity, Wit
 fn try_spawn_welcome_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    strings: Strings,
     query: Query<&WelcomeScreen>,
 ) {
     if !query.is_empty() {
@@ -81,7 +84,7 @@ fn try_spawn_welcome_screen(
 
     let welcome_text = commands
         .spawn((
-            Text::new("Tower Tumble\n\nCarefully remove\nblocks without\ntoppling the tower\n\nClick to Start"),
+            Text::new(strings.get("tower_tumble.welcome")),
             TextColor(Color::WHITE),
             TextFont {
                 font: asset_server.load(FONT),
@@ -109,15 +112,16 @@ fn try_spawn_level_complete(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     level_settings: Res<LevelSettings>,
-    mut game_progress: ResMut<GameProgress>,
+    game_progress: Res<GameProgress>,
+    mut score: ResMut<ScoreKeeper>,
+    strings: Strings,
     query: Query<&LevelCompleteScreen>,
 ) {
     if !query.is_empty() {
         return;
     }
 
-    let time_bonus = (game_progress.level_timer.remaining_secs() as u32) * 5;
-    game_progress.add_time_bonus();
+    let time_bonus = game_progress.add_time_bonus(&mut score);
 
     let overlay = commands
         .spawn((
@@ -135,12 +139,14 @@ fn try_spawn_level_complete(
 
     let complete_text = commands
         .spawn((
-            Text::new(format!(
-                "Level {} Complete!\n\nScore: {}\nTime Bonus: +{}\nTotal Score: {}\n\nClick to Continue",
-                level_settings.level,
-                game_progress.score - time_bonus,
-                time_bonus,
-                game_progress.score
+            Text::new(strings.format(
+                "tower_tumble.level_complete",
+                &[
+                    ("level", level_settings.level.into()),
+                    ("score", (score.total() - time_bonus).into()),
+                    ("time_bonus", time_bonus.into()),
+                    ("total", score.total().into()),
+                ],
             )),
             TextColor(Color::WHITE),
             TextFont {
@@ -165,7 +171,7 @@ fn handle_level_complete_input(
     }
 }
 
-fn spawn_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn spawn_game_ui(mut commands: Commands, asset_server: Res<AssetServer>, strings: Strings) {
     let ui_root = commands
         .spawn((
             Node {
@@ -180,7 +186,7 @@ fn spawn_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     let score_text = commands
         .spawn((
-            Text::new("Score: 0"),
+            Text::new(strings.format("hud.score", &[("score", 0_u32.into())])),
             UITextType::Score,
             TextFont {
                 font: asset_server.load(FONT),
@@ -199,7 +205,7 @@ fn spawn_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     let level_text = commands
         .spawn((
-            Text::new("Level: 1"),
+            Text::new(strings.format("tower_tumble.level", &[("level", 1_u32.into())])),
             UITextType::Level,
             TextFont {
                 font: asset_server.load(FONT),
@@ -218,7 +224,7 @@ fn spawn_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     let timer_text = commands
         .spawn((
-            Text::new("Time: 90"),
+            Text::new(strings.format("tower_tumble.time", &[("time", 90_u32.into())])),
             UITextType::Timer,
             TextFont {
                 font: asset_server.load(FONT),
@@ -237,7 +243,13 @@ fn spawn_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     let blocks_text = commands
         .spawn((
-            Text::new("Blocks: 0/15"),
+            Text::new(strings.format(
+                "tower_tumble.blocks",
+                &[
+                    ("removed", 0_u32.into()),
+                    ("total", MAX_BLOCKS_REMOVED.into()),
+                ],
+            )),
             UITextType::Blocks,
             TextFont {
                 font: asset_server.load(FONT),
@@ -265,22 +277,33 @@ fn spawn_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
 fn update_game_ui(
     mut text_query: Query<(&mut Text, &UITextType), With<GameUI>>,
     game_progress: Res<GameProgress>,
+    score: Res<ScoreKeeper>,
     level_settings: Res<LevelSettings>,
+    strings: Strings,
 ) {
     for (mut text, text_type) in &mut text_query {
         match text_type {
             UITextType::Score => {
-                text.0 = format!("Score: {}", game_progress.score);
+                text.0 = strings.format("hud.score", &[("score", score.total().into())]);
             }
             UITextType::Level => {
-                text.0 = format!("Level: {}", level_settings.level);
+                text.0 = strings.format(
+                    "tower_tumble.level",
+                    &[("level", level_settings.level.into())],
+                );
             }
             UITextType::Timer => {
                 let time_remaining = game_progress.level_timer.remaining_secs() as u32;
-                text.0 = format!("Time: {time_remaining}");
+                text.0 = strings.format("tower_tumble.time", &[("time", time_remaining.into())]);
             }
             UITextType::Blocks => {
-                text.0 = format!("Blocks: {}/15", game_progress.blocks_removed);
+                text.0 = strings.format(
+                    "tower_tumble.blocks",
+                    &[
+                        ("removed", game_progress.blocks_removed.into()),
+                        ("total", MAX_BLOCKS_REMOVED.into()),
+                    ],
+                );
             }
         }
     }
@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 use bevy::utils::Duration;
-use bits_helpers::emoji::{self, AtlasValidation, EmojiAtlas, EmojiPlugin};
+use bits_helpers::emoji::{EmojiPlugin, Emojis};
 use bits_helpers::input::just_pressed_world_position;
+use bits_helpers::loading::LoadingState;
+use bits_helpers::score::{ResultPolicy, ScoreEvent, ScoreKeeper};
 use bits_helpers::{WINDOW_HEIGHT, WINDOW_WIDTH, send_bit_message};
 use ribbit::WhackAMole;
-use ribbit_bits::{BitMessage, BitResult};
+use ribbit_bits::BitMessage;
 use ui::{BottomTextUI, ScoreUI, TimeUI};
 
 mod ribbit;
@@ -23,6 +25,8 @@ const HOLE_OFFSET: f32 = WINDOW_WIDTH / (COLUMN as f32);
 const HOLE_RADIUS: f32 = HOLE_OFFSET * HOLE_SCALE * 0.5;
 
 const POINTS: [i32; MOLE_VARIATIONS] = [1, -1, 5];
+// Moles must be told apart at a glance, so the legend avoids look-alike emojis
+const MOLE_MIN_DISTANCE: f32 = 0.35;
 
 #[derive(Component, Default)]
 struct LegendBase {
@@ -74,11 +78,6 @@ struct Grid {
     grid: Vec<Vec2>,
 }
 
-#[derive(Resource, Default)]
-struct GameProgress {
-    score: u32,
-}
-
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
 enum GameState {
     #[default]
@@ -94,14 +93,14 @@ pub fn run() {
         .add_plugins(ui::UIPlugin)
         .init_state::<GameState>()
         .insert_resource(setup_grid())
-        .insert_resource(GameProgress::default())
+        .insert_resource(ScoreKeeper::new(ResultPolicy::HighestScore).with_stars([10, 25, 50]))
         .add_systems(OnEnter(GameState::Init), init_enter)
+        .add_systems(OnEnter(LoadingState::Loaded), init)
         .add_systems(OnEnter(GameState::Game), game_enter)
         .add_systems(OnExit(GameState::Game), game_exit)
         .add_systems(
             Update,
             (
-                init.run_if(in_state(GameState::Init)),
                 update,
                 update_mole,
                 update_feedback,
@@ -113,8 +112,8 @@ pub fn run() {
         .run();
 }
 
-fn reset(mut commands: Commands, mut next_state: ResMut<NextState<GameState>>) {
-    commands.insert_resource(GameProgress::default());
+fn reset(mut score: ResMut<ScoreKeeper>, mut next_state: ResMut<NextState<GameState>>) {
+    score.reset();
     next_state.set(GameState::Game);
 }
 
@@ -141,10 +140,8 @@ fn init_enter(
     bottom_text.set_visiblity(Visibility::Hidden);
 }
 
-fn init(validation: Res<AtlasValidation>, mut next_state: ResMut<NextState<GameState>>) {
-    if emoji::is_emoji_system_ready(&validation) {
-        next_state.set(GameState::Game);
-    }
+fn init(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Game);
 }
 
 fn setup_grid() -> Grid {
@@ -215,13 +212,23 @@ fn spawn_legend_base(commands: &mut Commands) {
 
 fn game_enter(
     mut commands: Commands,
-    mut game: ResMut<GameProgress>,
+    mut score: ResMut<ScoreKeeper>,
     mut score_text: ResMut<ScoreUI>,
-    atlas: Res<EmojiAtlas>,
-    validation: Res<AtlasValidation>,
+    emojis: Emojis,
     query: Query<(&LegendBase, &GlobalTransform)>,
+    mut next_state: ResMut<NextState<GameState>>,
 ) {
-    let indices = emoji::get_random_emojis(&atlas, &validation, MOLE_VARIATIONS);
+    let indices = match emojis
+        .pick_distinct(MOLE_VARIATIONS, MOLE_MIN_DISTANCE)
+        .or_else(|_| emojis.pick_random(MOLE_VARIATIONS))
+    {
+        Ok(indices) => indices,
+        Err(err) => {
+            error!("Could not pick the moles, skipping the round: {err}");
+            next_state.set(GameState::Result);
+            return;
+        }
+    };
 
     commands
         .spawn(GameManager {
@@ -237,24 +244,21 @@ fn game_enter(
             },
         })
         .insert(LifespanGame);
-    spawn_legend(&mut commands, &indices, &atlas, &validation, &query);
-    game.score = 0;
-    score_text.update(game.score);
+    spawn_legend(&mut commands, &indices, &emojis, &query);
+    score.reset();
+    score_text.update(score.total());
 }
 
 fn spawn_legend(
     commands: &mut Commands,
     indices: &[usize],
-    atlas: &Res<EmojiAtlas>,
-    validation: &Res<AtlasValidation>,
+    emojis: &Emojis,
     query: &Query<(&LegendBase, &GlobalTransform)>,
 ) {
     // It needs to be tied to the UI position (legend base) eventually...
     for (base, _transform) in query {
-        if let Some(id) = emoji::spawn_emoji(
+        if let Ok(id) = emojis.spawn(
             commands,
-            atlas,
-            validation,
             *indices.get(base.index).expect(""),
             Transform {
                 translation: Vec3::new(
@@ -284,7 +288,7 @@ fn update(
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     touch_input: Res<Touches>,
     state: Res<State<GameState>>,
-    mut game: ResMut<GameProgress>,
+    mut score: ResMut<ScoreKeeper>,
     mut score_text: ResMut<ScoreUI>,
     mut next_state: ResMut<NextState<GameState>>,
     mut query: Query<(Entity, &Mole, &Transform)>,
@@ -309,16 +313,16 @@ fn update(
             if diff.length_squared() < HOLE_RADIUS * HOLE_RADIUS {
                 match mole.point {
                     x if x > 0 => {
-                        game.score = game.score.saturating_add(mole.point as u32);
+                        score.record(ScoreEvent::Combo(mole.point.unsigned_abs()));
                         spawn_feedback(&mut commands, transform.translation, mole.point);
                     }
                     x if x < 0 => {
-                        game.score = game.score.saturating_sub(mole.point.unsigned_abs());
+                        score.record(ScoreEvent::Penalty(mole.point.unsigned_abs()));
                         spawn_feedback(&mut commands, transform.translation, mole.point);
                     }
                     _ => (),
                 }
-                score_text.update(game.score);
+                score_text.update(score.total());
                 commands.entity(entity).despawn();
             }
         }
@@ -330,14 +334,17 @@ fn update_game_timer(
     time: Res<Time>,
     mut time_text: ResMut<TimeUI>,
     mut query: Query<(Entity, &mut GameTimer, &Wave)>,
+    score: Res<ScoreKeeper>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    let (_entity, mut game_timer, _wave) = query.single_mut();
+    let Ok((_entity, mut game_timer, _wave)) = query.get_single_mut() else {
+        return;
+    };
     game_timer.timer.tick(time.delta());
     time_text.update(game_timer.timer.remaining());
     if game_timer.timer.finished() {
         //commands.entity(entity).despawn();
-        send_bit_message(BitMessage::End(BitResult::Success));
+        send_bit_message(BitMessage::End(score.result()));
         next_state.set(GameState::Result);
     }
 }
@@ -347,10 +354,11 @@ fn update_wave(
     grid: Res<Grid>,
     time: Res<Time>,
     mut query: Query<(Entity, &mut Wave, &EmojiIndices)>,
-    atlas: Res<EmojiAtlas>,
-    validation: Res<AtlasValidation>,
+    emojis: Emojis,
 ) {
-    let (_entity, mut wave, emojis) = query.single_mut();
+    let Ok((_entity, mut wave, emoji_indices)) = query.get_single_mut() else {
+        return;
+    };
     wave.timer.tick(time.delta());
     if wave.timer.finished() {
         let mut positions = grid.grid.clone();
@@ -364,10 +372,9 @@ fn update_wave(
             spawn_mole(
                 &mut commands,
                 *pos,
-                &atlas,
-                &validation,
+                &emojis,
                 *variations.get(i).expect(""),
-                &emojis.indices,
+                &emoji_indices.indices,
             );
         }
         wave.count += 1;
@@ -386,19 +393,12 @@ fn update_mole(mut commands: Commands, time: Res<Time>, mut query: Query<(Entity
 fn spawn_mole(
     commands: &mut Commands,
     pos: Vec2,
-    atlas: &Res<EmojiAtlas>,
-    validation: &Res<AtlasValidation>,
+    emojis: &Emojis,
     variation: usize,
-    emojis: &[usize],
+    indices: &[usize],
 ) {
-    let index = *emojis.get(variation).expect("");
-    if let Some(id) = emoji::spawn_emoji(
-        commands,
-        atlas,
-        validation,
-        index,
-        Transform::from_xyz(pos.x, pos.y, 0.),
-    ) {
+    let index = *indices.get(variation).expect("");
+    if let Ok(id) = emojis.spawn(commands, index, Transform::from_xyz(pos.x, pos.y, 0.)) {
         let point = POINTS.get(variation).map_or(1, |p| *p);
         commands.entity(id).insert(Mole {
             timer: Timer::new(Duration::from_secs(1), TimerMode::Once),
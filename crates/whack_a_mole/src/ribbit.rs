@@ -1,8 +1,9 @@
 use bevy::prelude::*;
 use bits_helpers::RibbitMessageHandler;
+use bits_helpers::score::ScoreKeeper;
 use ribbit_bits::{BitDuration, BitResult};
 
-use crate::{GameProgress, GameState};
+use crate::GameState;
 
 #[derive(Default, Clone, Copy)]
 pub struct WhackAMole;
@@ -17,8 +18,7 @@ impl RibbitMessageHandler for WhackAMole {
         let mut next_state = world.resource_mut::<NextState<GameState>>();
         next_state.set(GameState::Result);
 
-        let progress = world.resource::<GameProgress>();
-        BitResult::HighestScore(progress.score.into())
+        world.resource::<ScoreKeeper>().result()
     }
 
     fn duration(_world: &mut bevy::prelude::World) -> BitDuration {
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use bevy::utils::Duration;
-use bits_helpers::emoji::{self, AtlasValidation, EmojiAtlas, EmojiPlugin};
+use bits_helpers::emoji::{EmojiPlugin, Emojis, emoji_contains_point};
+use bits_helpers::i18n::Strings;
 use bits_helpers::input::just_pressed_world_position;
+use bits_helpers::loading::LoadingState;
 use bits_helpers::{WINDOW_HEIGHT, WINDOW_WIDTH, send_bit_message};
 use ribbit::WheresWaldo;
 use ribbit_bits::{BitMessage, BitResult};
@@ -19,13 +21,13 @@ const BACKGROUND_COLOR: Color = Color::Srgba(Srgba {
 
 const SPRITE_SIZE_X: f32 = 32.;
 const SPRITE_SIZE_Y: f32 = 32.;
-
-const SPRITE_SCALE: f32 = SPRITE_SIZE_X / (emoji::EMOJI_SIZE.x as f32);
+const SPRITE_SIZE: Vec2 = Vec2::new(SPRITE_SIZE_X, SPRITE_SIZE_Y);
 
 const UI_Y: f32 = -BACKGROUND_SIZE_Y * 0.5 - 32.;
 const UI_RESULT_Y: f32 = UI_Y - 32.;
-
-const COLLISION_RADIUS: f32 = SPRITE_SIZE_X * 0.75;
+const UI_FONT_SIZE: f32 = 24.;
+// UI nodes are laid out from the top of the screen
+const HUD_TOP: f32 = WINDOW_HEIGHT * 0.5 - UI_Y - UI_FONT_SIZE * 0.5;
 
 const NUMBER_OF_CANDIDATES: u32 = 40;
 
@@ -41,6 +43,9 @@ struct Character;
 #[derive(Component)]
 struct Waldo;
 
+#[derive(Component)]
+struct WaldoHud;
+
 #[derive(Component)]
 struct FeedbackUI {
     timer: Timer,
@@ -98,13 +103,13 @@ pub fn run() {
             ),
         )
         .add_systems(OnEnter(GameState::Init), init_enter)
+        .add_systems(OnEnter(LoadingState::Loaded), init)
         .add_systems(OnEnter(GameState::Game), (clear_puzzle, game_enter))
         .add_systems(OnEnter(GameState::Reset), reset_enter)
         .add_systems(
             Update,
             (
                 mouse_events.in_set(GameSystemSet::Input),
-                init.run_if(in_state(GameState::Init)),
                 inquire_position
                     .in_set(GameSystemSet::Action)
                     .run_if(in_state(GameState::Game)),
@@ -141,15 +146,6 @@ fn setup_static_entities(commands: &mut Commands) {
         Transform::from_xyz(0., 0., -10.),
     ));
     // UI
-    commands.spawn((
-        Text2d::new("Where's   ?"),
-        TextFont {
-            font_size: 24.,
-            ..default()
-        },
-        TextColor(Color::WHITE),
-        Transform::from_translation(Vec3::new(0., UI_Y, 0.)),
-    ));
     spawn_progress_ui(commands);
 
     // Grid
@@ -170,10 +166,8 @@ fn setup_static_entities(commands: &mut Commands) {
     });
 }
 
-fn init(validation: Res<AtlasValidation>, mut next_state: ResMut<NextState<GameState>>) {
-    if emoji::is_emoji_system_ready(&validation) {
-        next_state.set(GameState::Game);
-    }
+fn init(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Game);
 }
 
 // For the game state
@@ -181,10 +175,9 @@ fn game_enter(
     mut commands: Commands,
     mut grid: ResMut<Grid>,
     mut progress: ResMut<GameProgress>,
-    atlas: Res<EmojiAtlas>,
-    validation: Res<AtlasValidation>,
+    emojis: Emojis,
 ) {
-    create_puzzle(&mut commands, &mut grid, &mut progress, &atlas, &validation);
+    create_puzzle(&mut commands, &mut grid, &mut progress, &emojis);
 }
 
 fn result(
@@ -217,10 +210,14 @@ fn clear_puzzle(
     mut commands: Commands,
     characters: Query<Entity, With<Character>>,
     game_timers: Query<Entity, With<GameTimer>>,
+    huds: Query<Entity, With<WaldoHud>>,
 ) {
     for character in characters.iter() {
         commands.entity(character).despawn();
     }
+    for hud in huds.iter() {
+        commands.entity(hud).despawn_recursive();
+    }
     for game_timer in game_timers.iter() {
         commands.entity(game_timer).despawn();
     }
@@ -262,15 +259,16 @@ fn create_puzzle(
     commands: &mut Commands,
     grid: &mut ResMut<Grid>,
     progress: &mut ResMut<GameProgress>,
-    atlas: &Res<EmojiAtlas>,
-    validation: &Res<AtlasValidation>,
+    emojis: &Emojis,
 ) {
     // Get random emojis for the puzzle
-    let selected_indices =
-        emoji::get_random_emojis(atlas, validation, NUMBER_OF_CANDIDATES as usize);
-    if selected_indices.is_empty() {
-        return;
-    }
+    let selected_indices = match emojis.pick_random(NUMBER_OF_CANDIDATES as usize) {
+        Ok(indices) => indices,
+        Err(err) => {
+            error!("Could not pick puzzle emojis: {err}");
+            return;
+        }
+    };
 
     let mut grid_positions = Vec::new();
     for x in 0..8 {
@@ -291,49 +289,27 @@ fn create_puzzle(
     let waldo_position = grid_positions
         .pop()
         .expect("grid_positions should not be empty");
-    if let Some(waldo_entity) = emoji::spawn_emoji(
+    if let Ok(waldo_entity) = emojis.spawn_sized(
         commands,
-        atlas,
-        validation,
         waldo_index,
-        Transform {
-            translation: get_random_transform(waldo_position).translation,
-            scale: Vec3::splat(SPRITE_SCALE),
-            ..default()
-        },
+        SPRITE_SIZE,
+        Transform::from_translation(get_random_transform(waldo_position).translation),
     ) {
         commands.entity(waldo_entity).insert((Character, Waldo));
     }
 
-    if let Some(waldo_entity) = emoji::spawn_emoji(
-        commands,
-        atlas,
-        validation,
-        waldo_index,
-        Transform {
-            translation: Vec3::new(50., UI_Y + 5.0, 0.),
-            scale: Vec3::splat(SPRITE_SCALE),
-            ..default()
-        },
-    ) {
-        commands.entity(waldo_entity).insert(Character);
-    }
+    spawn_waldo_hud(commands, emojis, waldo_index);
 
     for (position, &index) in grid_positions
         .iter()
         .zip(selected_indices.iter().skip(1))
         .take(NUMBER_OF_CANDIDATES as usize - 1)
     {
-        if let Some(entity) = emoji::spawn_emoji(
+        if let Ok(entity) = emojis.spawn_sized(
             commands,
-            atlas,
-            validation,
             index,
-            Transform {
-                translation: get_random_transform(*position).translation,
-                scale: Vec3::splat(SPRITE_SCALE),
-                ..default()
-            },
+            SPRITE_SIZE,
+            Transform::from_translation(get_random_transform(*position).translation),
         ) {
             commands.entity(entity).insert(Character);
         }
@@ -348,8 +324,10 @@ fn create_puzzle(
 fn inquire_position(
     mut commands: Commands,
     mut inquire_event: EventReader<InquireEvent>,
-    character_query: Query<(&Transform, &Character), Without<Waldo>>,
-    waldo_query: Query<(&Transform, &Character), With<Waldo>>,
+    character_query: Query<(&Sprite, &GlobalTransform), (With<Character>, Without<Waldo>)>,
+    waldo_query: Query<(&Sprite, &GlobalTransform), (With<Character>, With<Waldo>)>,
+    emojis: Emojis,
+    strings: Strings,
     mut progress: ResMut<GameProgress>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
@@ -369,10 +347,8 @@ fn inquire_position(
             return;
         }
 
-        let squared_radius = COLLISION_RADIUS * COLLISION_RADIUS;
-        for (transform, _character) in &waldo_query {
-            let dist = ev_pos.distance_squared(transform.translation.truncate());
-            if dist < squared_radius {
+        for (sprite, transform) in &waldo_query {
+            if emoji_contains_point(&emojis, sprite, transform, ev_pos) {
                 progress.result = true;
                 send_bit_message(BitMessage::End(BitResult::Success));
                 next_state.set(GameState::Result);
@@ -380,12 +356,11 @@ fn inquire_position(
             }
         }
 
-        for (transform, _character) in &character_query {
-            let dist = ev_pos.distance_squared(transform.translation.truncate());
-            if dist < squared_radius {
+        for (sprite, transform) in &character_query {
+            if emoji_contains_point(&emojis, sprite, transform, ev_pos) {
                 progress.mistakes += 1;
                 if progress.mistakes < 3 {
-                    spawn_feedback_ui(&mut commands, "It's not me!", 1);
+                    spawn_feedback_ui(&mut commands, &strings.get("wheres_waldo.not_me"), 1);
                 } else {
                     send_bit_message(BitMessage::End(BitResult::Failure));
                     next_state.set(GameState::Result);
@@ -397,6 +372,35 @@ fn inquire_position(
 }
 
 // For UI
+fn spawn_waldo_hud(commands: &mut Commands, emojis: &Emojis, waldo_index: usize) {
+    let font = TextFont {
+        font_size: UI_FONT_SIZE,
+        ..default()
+    };
+    let Ok(waldo_span) = emojis.ui_span(waldo_index, &font) else {
+        return;
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(HUD_TOP),
+                width: Val::Percent(100.),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            WaldoHud,
+        ))
+        .with_children(|parent| {
+            parent.spawn((Text::new("Where's"), font.clone(), TextColor(Color::WHITE)));
+            parent.spawn(waldo_span);
+            parent.spawn((Text::new("?"), font, TextColor(Color::WHITE)));
+        });
+}
+
 fn spawn_feedback_ui(commands: &mut Commands, text: &str, secs: u64) {
     commands.spawn((
         Text2d::new(text),